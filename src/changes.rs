@@ -0,0 +1,182 @@
+use crate::{
+    controls::{ControlData, ControlRenderData, FieldGetter, UnparseFn},
+    form::FormToolData,
+};
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+
+/// A control whose value differs between two versions of the form data.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FieldChange {
+    /// The name of the control.
+    pub name: String,
+    /// The display value of the control in the initial form data.
+    pub old: String,
+    /// The display value of the control in the current form data.
+    pub new: String,
+}
+
+/// A function that compares a control's field in the initial and current
+/// form data, returning the change if there is one.
+pub(crate) trait ChangeFn<FD: 'static>:
+    Fn(RwSignal<FD>, RwSignal<FD>) -> Option<FieldChange> + 'static
+{
+}
+impl<FD: 'static, F> ChangeFn<FD> for F where
+    F: Fn(RwSignal<FD>, RwSignal<FD>) -> Option<FieldChange> + 'static
+{
+}
+
+/// Creates the [`ChangeFn`] for a control, if it has a name.
+///
+/// The fields are compared with their [`PartialEq`] implementation, and
+/// the values are shown the same way as when reviewing the form.
+pub(crate) fn change_fn<FD: FormToolData, C: ControlData<FD>, FDT: PartialEq + 'static>(
+    render_data: Rc<ControlRenderData<FD::Style, C>>,
+    getter: Rc<dyn FieldGetter<FD, FDT>>,
+    unparse_fn: Rc<dyn UnparseFn<C::ReturnType, FDT>>,
+) -> Option<Rc<dyn ChangeFn<FD>>> {
+    let name = render_data
+        .data
+        .name()
+        .filter(|name| !name.is_empty())?
+        .to_string();
+
+    let change_fn = move |initial: RwSignal<FD>, current: RwSignal<FD>| {
+        let old = initial.with_untracked(|fd| getter(fd));
+        let new = current.with_untracked(|fd| getter(fd));
+        if old == new {
+            return None;
+        }
+        let display = |fd: RwSignal<FD>, value: FDT| {
+            (render_data.data)
+                .display_value(fd, &unparse_fn(value))
+                .unwrap_or_default()
+        };
+        Some(FieldChange {
+            name: name.clone(),
+            old: display(initial, old),
+            new: display(current, new),
+        })
+    };
+    Some(Rc::new(change_fn))
+}
+
+/// Gets the changes between the initial and current form data, in the
+/// order the controls were added.
+pub(crate) fn changes<FD: 'static>(
+    change_fns: &[Rc<dyn ChangeFn<FD>>],
+    initial: RwSignal<FD>,
+    current: RwSignal<FD>,
+) -> Vec<FieldChange> {
+    change_fns
+        .iter()
+        .filter_map(|change_fn| change_fn(initial, current))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{styles::GridFormStyle, FormBuilder};
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Profile {
+        nickname: String,
+        plan: String,
+        newsletter: bool,
+        notes: String,
+    }
+
+    impl FormToolData for Profile {
+        type Style = GridFormStyle;
+        type Context = ();
+
+        fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+            fb.checkbox(|c| {
+                c.named("newsletter")
+                    .getter(|fd| fd.newsletter)
+                    .setter(|fd, value| fd.newsletter = value)
+                    .parse_from()
+            })
+            .select(|c| {
+                c.named("plan")
+                    .getter(|fd| fd.plan.clone())
+                    .setter(|fd, value| fd.plan = value)
+                    .parse_string()
+                    .with_options_valued(vec![("Free", "free"), ("Pro", "pro")].into_iter())
+            })
+            .text_input(|c| {
+                c.named("nickname")
+                    .getter(|fd| fd.nickname.clone())
+                    .setter(|fd, value| fd.nickname = value)
+                    .parse_string()
+            })
+            // controls without a name are not compared
+            .text_input(|c| {
+                c.getter(|fd| fd.notes.clone())
+                    .setter(|fd, value| fd.notes = value)
+                    .parse_string()
+            })
+        }
+    }
+
+    /// Gets the changes inside of a reactive runtime, which the validator
+    /// needs to compare the form data.
+    fn changes(initial: &Profile, current: &Profile) -> Vec<FieldChange> {
+        let runtime = create_runtime();
+        let changes = Profile::get_validator(()).changes(initial, current);
+        runtime.dispose();
+        changes
+    }
+
+    fn change(name: &str, old: &str, new: &str) -> FieldChange {
+        FieldChange {
+            name: name.to_string(),
+            old: old.to_string(),
+            new: new.to_string(),
+        }
+    }
+
+    #[test]
+    fn no_changes() {
+        let fd = Profile {
+            nickname: String::from("al"),
+            ..Profile::default()
+        };
+        assert_eq!(changes(&fd, &fd.clone()), []);
+    }
+
+    #[test]
+    fn changes_in_control_order() {
+        let initial = Profile {
+            plan: String::from("free"),
+            ..Profile::default()
+        };
+        let current = Profile {
+            nickname: String::from("al"),
+            plan: String::from("pro"),
+            newsletter: true,
+            notes: String::from("not compared"),
+        };
+        assert_eq!(
+            changes(&initial, &current),
+            [
+                change("newsletter", "No", "Yes"),
+                change("plan", "Free", "Pro"),
+                change("nickname", "", "al"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unnamed_controls_skipped() {
+        let initial = Profile::default();
+        let current = Profile {
+            notes: String::from("changed"),
+            ..Profile::default()
+        };
+        assert_eq!(changes(&initial, &current), []);
+    }
+}
@@ -44,6 +44,8 @@ impl<FD: FormToolData> VanityControlData<FD> for ButtonBuildData<FD> {
 
         let new_control = ControlRenderData {
             styles: control.styles.clone(),
+            id: control.id.clone(),
+            required: control.required,
             data: ButtonData { action },
         };
         let new_control = Rc::new(new_control);
@@ -25,6 +25,34 @@ impl<FD: FormToolData> ControlData<FD> for CheckboxData {
     ) -> View {
         fs.checkbox(control, value_getter, value_setter)
     }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    fn display_value(&self, _fd: RwSignal<FD>, value: &Self::ReturnType) -> Option<String> {
+        let text = match value {
+            true => "Yes",
+            false => "No",
+        };
+        Some(text.to_string())
+    }
+
+    fn value_to_string(&self, value: &Self::ReturnType) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    fn value_from_string(&self, value: &str) -> Option<Self::ReturnType> {
+        match value {
+            "true" | "checked" | "on" => Some(true),
+            "false" | "" => Some(false),
+            _ => None,
+        }
+    }
 }
 
 impl<FD: FormToolData> FormBuilder<FD> {
@@ -11,12 +11,55 @@ impl<FD: FormToolData> FormBuilder<FD> {
     /// This creates a subsection of the form that controls can be added to
     /// like a normal form.
     pub fn group(mut self, builder: impl Fn(FormBuilder<FD>) -> FormBuilder<FD>) -> Self {
-        let mut group_builder = FormBuilder::new_group(self.cx.clone());
+        let id = self.ids.next_id();
+        let mut group_builder = FormBuilder::new_group(&self);
         group_builder = builder(group_builder);
 
         for validation in group_builder.validations {
             self.validations.push(validation);
         }
+        for warning in group_builder.warnings {
+            self.warnings.push(warning);
+        }
+        for derivation in group_builder.derivations {
+            self.derivations.push(derivation);
+        }
+        for query_field in group_builder.query_fields {
+            self.query_fields.push(query_field);
+        }
+        for change_fn in group_builder.change_fns {
+            self.change_fns.push(change_fn);
+        }
+        for draft_reset in group_builder.draft_resets {
+            self.draft_resets.push(draft_reset);
+        }
+        if group_builder.draft.is_some() {
+            self.draft = group_builder.draft;
+        }
+        if group_builder.unsaved_guard.is_some() {
+            self.unsaved_guard = group_builder.unsaved_guard;
+        }
+        if group_builder.history.is_some() {
+            self.history = group_builder.history;
+        }
+
+        let review_fns = group_builder.review_fns;
+        let review_id = id.clone();
+        let review_styles = group_builder.styles.clone();
+        let review_fn = move |fs: Rc<FD::Style>, fd: RwSignal<FD>| {
+            let views: Vec<_> = review_fns
+                .into_iter()
+                .map(|r_fn| r_fn(fs.clone(), fd))
+                .collect();
+
+            fs.group(Rc::new(ControlRenderData {
+                data: views.collect_view(),
+                id: review_id,
+                required: false,
+                styles: review_styles,
+            }))
+        };
+        self.review_fns.push(Box::new(review_fn));
 
         let render_fn = move |fs: Rc<FD::Style>, fd: RwSignal<FD>| {
             let (views, validation_cbs): (Vec<_>, Vec<_>) = group_builder
@@ -27,6 +70,8 @@ impl<FD: FormToolData> FormBuilder<FD> {
 
             let render_data = Rc::new(ControlRenderData {
                 data: views.collect_view(),
+                id,
+                required: false,
                 styles: group_builder.styles,
             });
 
@@ -30,6 +30,10 @@ impl<FD: FormToolData> VanityControlData<FD> for HeadingData {
     ) -> View {
         fs.heading(control, value_getter)
     }
+
+    fn shown_in_review() -> bool {
+        true
+    }
 }
 impl<FD: FormToolData> GetterVanityControlData<FD> for HeadingData {}
 
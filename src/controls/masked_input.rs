@@ -0,0 +1,486 @@
+use super::{
+    parse_error_message, text_input::TextInputData, BuilderCxFn, BuilderFn, ControlBuilder,
+    ControlData, ControlRenderData, UpdateEvent, ValidatedControlData, ValidationState,
+};
+use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
+use leptos::{document, RwSignal, Signal, SignalGet, SignalSetter, View};
+use std::{rc::Rc, str::FromStr};
+use wasm_bindgen::JsCast;
+
+/// A single position of an [`InputMask`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MaskToken {
+    /// A digit, from the `9` in a pattern.
+    Digit,
+    /// A letter, from the `a` in a pattern.
+    Letter,
+    /// A letter that is made uppercase, from the `A` in a pattern.
+    UpperLetter,
+    /// A letter or digit, from the `*` in a pattern.
+    Alphanumeric,
+    /// A character that is inserted by the mask.
+    Literal(char),
+}
+
+impl MaskToken {
+    /// Gets the character to put in this position for `c`, if this position
+    /// accepts it.
+    fn accept(&self, c: char) -> Option<char> {
+        match self {
+            MaskToken::Digit => c.is_ascii_digit().then_some(c),
+            MaskToken::Letter => c.is_alphabetic().then_some(c),
+            MaskToken::UpperLetter => c.is_alphabetic().then(|| c.to_ascii_uppercase()),
+            MaskToken::Alphanumeric => c.is_alphanumeric().then_some(c),
+            MaskToken::Literal(_) => None,
+        }
+    }
+}
+
+/// A pattern that formats the text of a [`MaskedInputData`] as the user
+/// types.
+///
+/// In the pattern, `9` is a digit, `a` is a letter, `A` is a letter that is
+/// made uppercase and `*` is a letter or digit. Any other character is
+/// inserted by the mask, and `\` inserts the next character as is, like
+/// `\9` for a literal 9. For example, `(999) 999-9999` for a phone number,
+/// or `AA99 9999 9999 9999 9999 99` for a German IBAN.
+///
+/// The characters the user types are the raw value, which is what is kept
+/// in the form data. The characters inserted by the mask are only shown.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct InputMask {
+    tokens: Vec<MaskToken>,
+}
+
+impl InputMask {
+    /// Creates an [`InputMask`] from its pattern.
+    pub fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            let token = match c {
+                '9' => MaskToken::Digit,
+                'a' => MaskToken::Letter,
+                'A' => MaskToken::UpperLetter,
+                '*' => MaskToken::Alphanumeric,
+                '\\' => match chars.next() {
+                    Some(escaped) => MaskToken::Literal(escaped),
+                    None => MaskToken::Literal('\\'),
+                },
+                c => MaskToken::Literal(c),
+            };
+            tokens.push(token);
+        }
+        InputMask { tokens }
+    }
+
+    /// Gets the number of characters the user needs to type to fill the
+    /// mask.
+    pub fn len(&self) -> usize {
+        self.tokens
+            .iter()
+            .filter(|t| !matches!(t, MaskToken::Literal(_)))
+            .count()
+    }
+
+    /// Checks if the mask has no characters for the user to type, in which
+    /// case the text is not formatted at all.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Formats the raw value with the mask.
+    ///
+    /// Characters that don't fit the next position are skipped, and the
+    /// inserted characters are only added up to the last raw character that
+    /// fits, so the user can keep typing.
+    pub fn format(&self, raw: &str) -> String {
+        if self.is_empty() {
+            return raw.to_string();
+        }
+
+        let mut raw = raw.chars();
+        let mut formatted = String::new();
+        // the inserted characters since the last raw character, which are
+        // only added once another raw character fits
+        let mut literals = String::new();
+        for token in self.tokens.iter() {
+            if let MaskToken::Literal(c) = token {
+                literals.push(*c);
+                continue;
+            }
+            match raw.by_ref().find_map(|c| token.accept(c)) {
+                Some(c) => {
+                    formatted.push_str(&literals);
+                    literals.clear();
+                    formatted.push(c);
+                }
+                None => break,
+            }
+        }
+        formatted
+    }
+
+    /// Gets the raw value from the text shown in the input, removing the
+    /// characters inserted by the mask.
+    pub fn unmask(&self, value: &str) -> String {
+        if self.is_empty() {
+            return value.to_string();
+        }
+        let formatted = self.format(&self.extract(value));
+        formatted
+            .chars()
+            .zip(self.tokens.iter())
+            .filter(|(_, t)| !matches!(t, MaskToken::Literal(_)))
+            .map(|(c, _)| c)
+            .collect()
+    }
+
+    /// Checks if the raw value fills the whole mask.
+    pub fn is_complete(&self, raw: &str) -> bool {
+        self.unmask(raw).chars().count() == self.len()
+    }
+
+    /// Gets the placeholder showing the shape of the mask, with an `_` for
+    /// each character the user needs to type.
+    pub fn placeholder(&self) -> String {
+        self.tokens
+            .iter()
+            .map(|t| match t {
+                MaskToken::Literal(c) => *c,
+                _ => '_',
+            })
+            .collect()
+    }
+
+    /// Takes the characters the user typed out of the text shown in the
+    /// input.
+    ///
+    /// The text is walked along with the mask, so the inserted characters
+    /// are skipped even if they are letters or digits, like the `1` of a
+    /// `+1 (999) 999-9999` mask.
+    fn extract(&self, value: &str) -> String {
+        let mut raw = String::new();
+        let mut position = 0;
+        for c in value.chars() {
+            if let Some(MaskToken::Literal(literal)) = self.tokens.get(position) {
+                if *literal == c {
+                    position += 1;
+                    continue;
+                }
+            }
+            if !c.is_alphanumeric() {
+                continue;
+            }
+            raw.push(c);
+            // move past the next position the user types in
+            position += self.tokens[position.min(self.tokens.len())..]
+                .iter()
+                .position(|t| !matches!(t, MaskToken::Literal(_)))
+                .map_or(self.tokens.len(), |i| i + 1);
+        }
+        raw
+    }
+
+    /// Gets where the caret should go in the formatted text, so it stays
+    /// after the same raw character as it was in the typed text.
+    fn caret_position(&self, value: &str, caret: usize, formatted: &str) -> usize {
+        let before: String = value.chars().take(caret).collect();
+        let raw_before = self.extract(&before).chars().count();
+
+        let mut position = 0;
+        let mut count = 0;
+        for (i, token) in self.tokens.iter().enumerate() {
+            if count == raw_before || i >= formatted.chars().count() {
+                break;
+            }
+            if !matches!(token, MaskToken::Literal(_)) {
+                count += 1;
+                position = i + 1;
+            }
+        }
+        position
+    }
+
+    /// Writes the formatted text into the focused input, keeping the caret
+    /// after the same raw character.
+    ///
+    /// This has to be done right away, as the form data doesn't change if
+    /// the user types a character that doesn't fit the mask, so the input
+    /// would not be updated otherwise.
+    fn write_formatted(&self, value: &str, formatted: &str) {
+        let Some(input) = document()
+            .active_element()
+            .and_then(|e| e.dyn_into::<web_sys::HtmlInputElement>().ok())
+        else {
+            return;
+        };
+        // only the input that was typed in is changed
+        if input.value() != value {
+            return;
+        }
+        let caret = input.selection_start().ok().flatten().unwrap_or_default() as usize;
+        let caret = self.caret_position(value, caret, formatted) as u32;
+        input.set_value(formatted);
+        let _ = input.set_selection_range(caret, caret);
+    }
+}
+
+impl From<&str> for InputMask {
+    fn from(pattern: &str) -> Self {
+        InputMask::new(pattern)
+    }
+}
+
+/// Data used for the masked input control.
+///
+/// This is a text input that formats its text with an [`InputMask`] as the
+/// user types. The control's value is the raw value, without the characters
+/// inserted by the mask.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct MaskedInputData {
+    pub input: TextInputData,
+    pub mask: InputMask,
+}
+
+impl<FD: FormToolData> ControlData<FD> for MaskedInputData {
+    type ReturnType = String;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let mut input = control.data.input.clone();
+        if input.placeholder.is_none() && !control.data.mask.is_empty() {
+            input.placeholder = Some(control.data.mask.placeholder());
+        }
+        let new_control = ControlRenderData {
+            styles: control.styles.clone(),
+            id: control.id.clone(),
+            required: control.required,
+            data: input,
+        };
+
+        let mask = control.data.mask.clone();
+        let formatted_getter = Signal::derive(move || mask.format(&value_getter.get()));
+        let mask = control.data.mask.clone();
+        let raw_setter = SignalSetter::map(move |value: String| {
+            let raw = mask.unmask(&value);
+            mask.write_formatted(&value, &mask.format(&raw));
+            value_setter.set(raw);
+        });
+
+        fs.text_input(
+            Rc::new(new_control),
+            formatted_getter,
+            raw_setter,
+            validation_state,
+        )
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.input.name)
+    }
+
+    fn label(&self) -> Option<&str> {
+        self.input.label.as_deref()
+    }
+
+    fn display_value(&self, _fd: RwSignal<FD>, value: &Self::ReturnType) -> Option<String> {
+        Some(self.mask.format(value))
+    }
+
+    fn value_to_string(&self, value: &Self::ReturnType) -> Option<String> {
+        Some(value.clone())
+    }
+
+    fn value_from_string(&self, value: &str) -> Option<Self::ReturnType> {
+        Some(value.to_string())
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for MaskedInputData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a masked input control and adds it to the form.
+    pub fn masked_input<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, MaskedInputData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a masked input control using the form's context and adds it
+    /// to the form.
+    pub fn masked_input_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, MaskedInputData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, MaskedInputData, FDT> {
+    /// Sets the name of the masked input.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with. The formatted text is sent, so use
+    /// [`InputMask::unmask`] to get the raw value on the server.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.input.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the masked input.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.input.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the placeholder for the masked input.
+    ///
+    /// By default, the placeholder is the shape of the mask, see
+    /// [`InputMask::placeholder`].
+    pub fn placeholder(mut self, placeholder: impl ToString) -> Self {
+        self.data.input.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Sets the mask pattern, see [`InputMask`] for the pattern language.
+    pub fn mask(mut self, mask: impl Into<InputMask>) -> Self {
+        self.data.mask = mask.into();
+        self
+    }
+
+    /// Sets the text input to be the specified type.
+    ///
+    /// For things like phone numbers, this should be "tel", so mobile
+    /// keyboards show digits. Types that don't support text selection, like
+    /// "number" and "email", don't keep the caret in place.
+    pub fn input_type(mut self, input_type: &'static str) -> Self {
+        self.data.input.input_type = input_type;
+        self
+    }
+
+    /// Sets the event that is used to update the form data.
+    ///
+    /// The text is formatted as the user types with
+    /// [`UpdateEvent::OnInput`], which is the default.
+    pub fn update_on(mut self, event: UpdateEvent) -> Self {
+        self.data.input.update_event = event;
+        self
+    }
+}
+
+impl<FD, FDT> ControlBuilder<FD, MaskedInputData, FDT>
+where
+    FD: FormToolData,
+    FDT: FromStr + ToString,
+    <FDT as FromStr>::Err: ToString + 'static,
+{
+    /// Sets the parse functions to parse the raw value with the [`FromStr`]
+    /// and [`ToString`] traits.
+    ///
+    /// The characters inserted by the mask are never part of the value, so
+    /// a `(999) 999-9999` mask gives the field just the 10 digits.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_masked(mut self) -> Self {
+        self.parse_fn = None;
+        self.message_parse_fn = Some(Box::new(|raw: String| {
+            raw.parse::<FDT>().map_err(parse_error_message)
+        }));
+        self.unparse_fn = Some(Box::new(|field| field.to_string()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phone() -> InputMask {
+        InputMask::new("(999) 999-9999")
+    }
+
+    #[test]
+    fn format_inserts_literals() {
+        let mask = phone();
+        assert_eq!(mask.format(""), "");
+        assert_eq!(mask.format("1"), "(1");
+        assert_eq!(mask.format("123"), "(123");
+        assert_eq!(mask.format("1234"), "(123) 4");
+        assert_eq!(mask.format("1234567890"), "(123) 456-7890");
+        assert_eq!(mask.format("12345678901"), "(123) 456-7890");
+    }
+
+    #[test]
+    fn format_skips_rejected_chars() {
+        let mask = InputMask::new("(999) 999");
+        assert_eq!(mask.format("1a2-3"), "(123");
+        assert_eq!(mask.format("123x"), "(123");
+        assert_eq!(mask.format("123x4"), "(123) 4");
+        assert_eq!(mask.format("x"), "");
+
+        let plate = InputMask::new("AA-999");
+        assert_eq!(plate.format("ab123"), "AB-123");
+        assert_eq!(plate.format("a1b"), "AB");
+    }
+
+    #[test]
+    fn escaped_literals() {
+        let mask = InputMask::new("\\9\\a-999");
+        assert_eq!(mask.len(), 3);
+        assert_eq!(mask.placeholder(), "9a-___");
+        assert_eq!(mask.format("123"), "9a-123");
+        assert_eq!(mask.unmask("9a-123"), "123");
+    }
+
+    #[test]
+    fn leading_literal_digits() {
+        let mask = InputMask::new("+1 (999) 999-9999");
+        assert_eq!(mask.format("5551234567"), "+1 (555) 123-4567");
+        assert_eq!(mask.unmask("+1 (555) 123-4567"), "5551234567");
+        assert_eq!(mask.unmask("+1 (5"), "5");
+        // a pasted number without the prefix is not mistaken for it
+        assert_eq!(mask.unmask("1555"), "1555");
+    }
+
+    #[test]
+    fn unmask_removes_inserted_chars() {
+        let mask = phone();
+        assert_eq!(mask.unmask(""), "");
+        assert_eq!(mask.unmask("(123) 4"), "1234");
+        assert_eq!(mask.unmask("(123) 456-7890"), "1234567890");
+        assert_eq!(mask.unmask("123.456.7890"), "1234567890");
+        assert_eq!(InputMask::new("").unmask("as is"), "as is");
+    }
+
+    #[test]
+    fn complete() {
+        let mask = phone();
+        assert!(mask.is_complete("1234567890"));
+        assert!(mask.is_complete("(123) 456-7890"));
+        assert!(!mask.is_complete("123456789"));
+        assert!(!mask.is_complete(""));
+    }
+
+    #[test]
+    fn caret_after_inserted_literals() {
+        let mask = phone();
+        // typing the 4th digit at the end moves past the inserted ") "
+        assert_eq!(mask.caret_position("(1234", 5, "(123) 4"), 7);
+        // the caret stays after the same digit in the middle of the text
+        assert_eq!(mask.caret_position("(123) 4567", 3, "(123) 456-7"), 3);
+        assert_eq!(mask.caret_position("(123) 4567", 0, "(123) 456-7"), 0);
+        // typing the first digit moves past the leading literals
+        let mask = InputMask::new("+1 (999)");
+        assert_eq!(mask.caret_position("5", 1, "+1 (5"), 5);
+    }
+}
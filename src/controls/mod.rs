@@ -1,6 +1,17 @@
-use crate::{form::FormToolData, styles::FormStyle};
+use crate::{
+    form::FormToolData,
+    messages::{MessageCatalog, ValidationMessage},
+    styles::FormStyle,
+};
 use leptos::{RwSignal, Signal, SignalSetter, View};
-use std::{fmt::Display, rc::Rc, str::FromStr};
+use std::{
+    any::Any,
+    fmt::Display,
+    num::{ParseFloatError, ParseIntError},
+    rc::Rc,
+    str::FromStr,
+    time::Duration,
+};
 
 pub mod button;
 pub mod checkbox;
@@ -9,13 +20,17 @@ pub mod date;
 pub mod group;
 pub mod heading;
 pub mod hidden;
+pub mod masked_input;
+pub mod number_input;
 pub mod output;
+pub mod password;
 pub mod radio_buttons;
 pub mod select;
 pub mod slider;
 pub mod spacer;
 pub mod stepper;
 pub mod submit;
+pub mod switch;
 pub mod text_area;
 pub mod text_input;
 
@@ -24,6 +39,10 @@ pub trait BuilderCxFn<B, CX>: Fn(B, Rc<CX>) -> B {}
 pub trait ValidationFn<FD: ?Sized>: Fn(&FD) -> Result<(), String> + 'static {}
 pub trait ValidationCb: Fn() -> bool + 'static {}
 pub trait ParseFn<CR, FDT>: Fn(CR) -> Result<FDT, String> + 'static {}
+pub(crate) trait MessageParseFn<CR, FDT>:
+    Fn(CR) -> Result<FDT, ValidationMessage> + 'static
+{
+}
 pub trait UnparseFn<CR, FDT>: Fn(FDT) -> CR + 'static {}
 pub trait FieldGetter<FD, FDT>: Fn(&FD) -> FDT + 'static {}
 pub trait FieldSetter<FD, FDT>: Fn(&mut FD, FDT) + 'static {}
@@ -32,6 +51,7 @@ pub trait RenderFn<FS, FD: 'static>:
     FnOnce(Rc<FS>, RwSignal<FD>) -> (View, Option<Box<dyn ValidationCb>>) + 'static
 {
 }
+pub trait ReviewFn<FS, FD: 'static>: FnOnce(Rc<FS>, RwSignal<FD>) -> View + 'static {}
 
 // implement the traits for all valid types
 impl<B, T> BuilderFn<B> for T where T: Fn(B) -> B {}
@@ -39,6 +59,10 @@ impl<B, CX, T> BuilderCxFn<B, CX> for T where T: Fn(B, Rc<CX>) -> B {}
 impl<FDT, T> ValidationFn<FDT> for T where T: Fn(&FDT) -> Result<(), String> + 'static {}
 impl<T> ValidationCb for T where T: Fn() -> bool + 'static {}
 impl<CR, FDT, F> ParseFn<CR, FDT> for F where F: Fn(CR) -> Result<FDT, String> + 'static {}
+impl<CR, FDT, F> MessageParseFn<CR, FDT> for F where
+    F: Fn(CR) -> Result<FDT, ValidationMessage> + 'static
+{
+}
 impl<CR, FDT, F> UnparseFn<CR, FDT> for F where F: Fn(FDT) -> CR + 'static {}
 impl<FD, FDT, F> FieldGetter<FD, FDT> for F where F: Fn(&FD) -> FDT + 'static {}
 impl<FD, FDT, F> FieldSetter<FD, FDT> for F where F: Fn(&mut FD, FDT) + 'static {}
@@ -47,9 +71,18 @@ impl<FS, FD: 'static, F> RenderFn<FS, FD> for F where
     F: FnOnce(Rc<FS>, RwSignal<FD>) -> (View, Option<Box<dyn ValidationCb>>) + 'static
 {
 }
+impl<FS, FD: 'static, F> ReviewFn<FS, FD> for F where
+    F: FnOnce(Rc<FS>, RwSignal<FD>) -> View + 'static
+{
+}
 
 /// The possible states for a validated control
+///
+/// More states may be added in the future, so matches on this should have a
+/// wildcard arm. Use [`is_err`](Self::is_err) to check if the state should
+/// stop the form from being submitted.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[non_exhaustive]
 pub enum ValidationState {
     /// Parsing and validation passed. No errors
     #[default]
@@ -58,22 +91,30 @@ pub enum ValidationState {
     ParseError(String),
     /// Error when validating the field.
     ValidationError(String),
+    /// Validation passed, but with a warning.
+    ///
+    /// Unlike errors, warnings do not stop the form from being submitted.
+    Warning(String),
 }
 impl ValidationState {
-    /// Gets the error message if there is a parse or validation error.
+    /// Gets the message if there is a parse or validation error, or a
+    /// warning.
     pub fn msg(&self) -> Option<&String> {
         match self {
             ValidationState::Passed => None,
             ValidationState::ParseError(e) => Some(e),
             ValidationState::ValidationError(e) => Some(e),
+            ValidationState::Warning(w) => Some(w),
         }
     }
-    /// Takes the error message if there is a parse or validation error.
+    /// Takes the message if there is a parse or validation error, or a
+    /// warning.
     pub fn take_msg(self) -> Option<String> {
         match self {
             ValidationState::Passed => None,
             ValidationState::ParseError(e) => Some(e),
             ValidationState::ValidationError(e) => Some(e),
+            ValidationState::Warning(w) => Some(w),
         }
     }
 
@@ -82,8 +123,16 @@ impl ValidationState {
         matches!(self, ValidationState::Passed)
     }
     /// Returns true if self is either `ParseError` or `ValidationError`.
+    ///
+    /// This is false for `Warning`, as warnings don't stop the form from
+    /// being submitted.
     pub fn is_err(&self) -> bool {
-        !self.is_passed()
+        self.is_parse_err() || self.is_validation_err()
+    }
+
+    /// Returns true if self is `Warning`.
+    pub fn is_warning(&self) -> bool {
+        matches!(self, ValidationState::Warning(_))
     }
 
     /// Returns true if self is `ParseError`.
@@ -106,6 +155,24 @@ pub enum UpdateEvent {
     OnChange,
 }
 
+/// The possibilities for when a control validates its value.
+///
+/// Parse errors follow the same timing, and errors that have been fixed are
+/// always cleared right away.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ValidationTrigger {
+    /// Validate every time the control updates the form data.
+    #[default]
+    OnInput,
+    /// Validate when the control loses focus.
+    OnBlur,
+    /// Validate when the control loses focus for the first time, then every
+    /// time the control updates the form data.
+    AfterFirstBlur,
+    /// Only validate when the form is submitted.
+    OnSubmit,
+}
+
 /// A trait for the data needed to render an read-only control.
 pub trait VanityControlData<FD: FormToolData>: 'static {
     /// Builds the control, returning the [`View`] that was built.
@@ -115,6 +182,15 @@ pub trait VanityControlData<FD: FormToolData>: 'static {
         control: Rc<ControlRenderData<FS, Self>>,
         value_getter: Option<Signal<String>>,
     ) -> View;
+
+    /// Whether this control is also shown when reviewing the form, like
+    /// headings that title the sections of the form.
+    ///
+    /// Controls that don't show information about the form data, like
+    /// buttons, are left out of the review by default.
+    fn shown_in_review() -> bool {
+        false
+    }
 }
 pub trait GetterVanityControlData<FD: FormToolData>: VanityControlData<FD> {}
 
@@ -132,12 +208,75 @@ pub trait ControlData<FD: FormToolData>: 'static {
         value_setter: SignalSetter<Self::ReturnType>,
         validation_state: Signal<ValidationState>,
     ) -> View;
+
+    /// Gets the name of the control, which is the key that its value is
+    /// sent with.
+    ///
+    /// This is used for things like syncing the form data to the query
+    /// string. Controls without a name return `None`.
+    fn name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Gets the label of the control, if it has one.
+    fn label(&self) -> Option<&str> {
+        None
+    }
+
+    /// Converts the value of the control to a string, like for putting it in
+    /// the query string.
+    ///
+    /// Controls that can't convert their value to a string return `None`.
+    fn value_to_string(&self, _value: &Self::ReturnType) -> Option<String> {
+        None
+    }
+
+    /// Converts a string, like one from the query string, to the value of the
+    /// control.
+    ///
+    /// This is the reverse of [`value_to_string`](Self::value_to_string).
+    /// `None` is returned if the string isn't a valid value.
+    fn value_from_string(&self, _value: &str) -> Option<Self::ReturnType> {
+        None
+    }
+
+    /// Formats the value of the control for showing it to the user, like
+    /// when reviewing the form.
+    ///
+    /// By default, this uses [`value_to_string`](Self::value_to_string).
+    /// Controls with options should show the display text of the selected
+    /// option instead of its value.
+    fn display_value(&self, fd: RwSignal<FD>, value: &Self::ReturnType) -> Option<String> {
+        let _ = fd;
+        self.value_to_string(value)
+    }
+
+    /// Whether the value of the control is saved in drafts by
+    /// [`FormBuilder::autosave`](crate::FormBuilder::autosave), unless
+    /// overridden with [`ControlBuilder::in_draft`].
+    ///
+    /// Controls for secrets, like passwords, are left out by default, so
+    /// they aren't written to the browser's storage.
+    fn saved_in_draft(&self) -> bool {
+        true
+    }
 }
 pub trait ValidatedControlData<FD: FormToolData>: ControlData<FD> {}
 
 /// The data needed to render a interactive control of type `C`.
 pub struct ControlRenderData<FS: FormStyle + ?Sized, C: ?Sized> {
     pub styles: Vec<FS::StylingAttributes>,
+    /// The unique html id for this control.
+    ///
+    /// This is generated by the form, and is unique even if the same form
+    /// is rendered more than once on the page. Unlike the control's name,
+    /// which is the key the data is submitted with, this should be used for
+    /// the `id` of the rendered elements and the `for` of their labels.
+    pub id: String,
+    /// Whether the control is marked as required.
+    ///
+    /// This is only used for presentation, like setting `aria-required`.
+    pub required: bool,
     pub data: C,
 }
 
@@ -171,6 +310,8 @@ impl<FD: FormToolData, C: VanityControlData<FD>> VanityControlBuilder<FD, C> {
         BuiltVanityControlData {
             render_data: ControlRenderData {
                 data: self.data,
+                id: String::new(),
+                required: false,
                 styles: self.style_attributes,
             },
             getter: self.getter,
@@ -232,15 +373,32 @@ impl Display for ControlBuildError {
     }
 }
 
+/// Gets the message for an error from parsing a value with the
+/// [`FromStr`] or [`TryFrom`] traits.
+///
+/// Errors from parsing numbers are [`ValidationMessage::NotANumber`], other
+/// errors are [`ValidationMessage::ParseFailed`] with the error's text.
+pub(crate) fn parse_error_message<E: ToString + 'static>(error: E) -> ValidationMessage {
+    let any: &dyn Any = &error;
+    if any.is::<ParseIntError>() || any.is::<ParseFloatError>() {
+        return ValidationMessage::NotANumber;
+    }
+    ValidationMessage::ParseFailed(error.to_string())
+}
+
 /// The data returned from a control's build function.
 pub(crate) struct BuiltControlData<FD: FormToolData, C: ControlData<FD>, FDT> {
-    pub(crate) render_data: ControlRenderData<FD::Style, C>,
+    pub(crate) render_data: Rc<ControlRenderData<FD::Style, C>>,
     pub(crate) getter: Rc<dyn FieldGetter<FD, FDT>>,
     pub(crate) setter: Rc<dyn FieldSetter<FD, FDT>>,
-    pub(crate) parse_fn: Box<dyn ParseFn<C::ReturnType, FDT>>,
-    pub(crate) unparse_fn: Box<dyn UnparseFn<C::ReturnType, FDT>>,
+    pub(crate) parse_fn: Rc<dyn ParseFn<C::ReturnType, FDT>>,
+    pub(crate) unparse_fn: Rc<dyn UnparseFn<C::ReturnType, FDT>>,
     pub(crate) validation_fn: Option<Rc<dyn ValidationFn<FD>>>,
+    pub(crate) warning_fn: Option<Rc<dyn ValidationFn<FD>>>,
+    pub(crate) validation_trigger: Option<ValidationTrigger>,
+    pub(crate) debounce: Option<Duration>,
     pub(crate) show_when: Option<Rc<dyn ShowWhenFn<FD, FD::Context>>>,
+    pub(crate) in_draft: bool,
 }
 
 /// A builder for a interactive control.
@@ -250,8 +408,20 @@ pub struct ControlBuilder<FD: FormToolData, C: ControlData<FD>, FDT> {
     pub(crate) parse_fn: Option<Box<dyn ParseFn<C::ReturnType, FDT>>>,
     pub(crate) unparse_fn: Option<Box<dyn UnparseFn<C::ReturnType, FDT>>>,
     pub(crate) validation_fn: Option<Rc<dyn ValidationFn<FD>>>,
+    pub(crate) warning_fn: Option<Rc<dyn ValidationFn<FD>>>,
+    pub(crate) validation_trigger: Option<ValidationTrigger>,
+    /// How long to wait for the input to stop changing before updating the
+    /// form data, if the control is debounced.
+    pub(crate) debounce: Option<Duration>,
     pub(crate) style_attributes: Vec<<FD::Style as FormStyle>::StylingAttributes>,
     pub(crate) show_when: Option<Rc<dyn ShowWhenFn<FD, FD::Context>>>,
+    pub(crate) required: bool,
+    /// Whether the value is saved in drafts, if overridden.
+    pub(crate) in_draft: Option<bool>,
+    /// The parse function of the built in parse functions, whose errors are
+    /// rendered by the form's [`MessageCatalog`]. This is used if
+    /// `parse_fn` is not set.
+    pub(crate) message_parse_fn: Option<Box<dyn MessageParseFn<C::ReturnType, FDT>>>,
     pub data: C,
 }
 
@@ -265,15 +435,32 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
             parse_fn: None,
             unparse_fn: None,
             validation_fn: None,
+            warning_fn: None,
+            validation_trigger: None,
+            debounce: None,
             style_attributes: Vec::new(),
             show_when: None,
+            required: false,
+            in_draft: None,
+            message_parse_fn: None,
         }
     }
 
-    /// Builds the builder into the data needed to render the control.
+    /// Builds the builder into the data needed to render the control, with
+    /// the given html id.
+    ///
+    /// The errors of the built in parse functions are rendered with
+    /// `messages`, using the control's label or name as the field name.
     ///
     /// This fails if a required field was not specified.
-    pub(crate) fn build(self) -> Result<BuiltControlData<FD, C, FDT>, ControlBuildError> {
+    pub(crate) fn build(
+        self,
+        id: String,
+        messages: &Rc<dyn MessageCatalog>,
+    ) -> Result<BuiltControlData<FD, C, FDT>, ControlBuildError>
+    where
+        FDT: 'static,
+    {
         let getter = match self.getter {
             Some(getter) => getter,
             None => return Err(ControlBuildError::MissingGetter),
@@ -282,26 +469,45 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
             Some(setter) => setter,
             None => return Err(ControlBuildError::MissingSetter),
         };
-        let parse_fn = match self.parse_fn {
-            Some(parse_fn) => parse_fn,
-            None => return Err(ControlBuildError::MissingParseFn),
-        };
+        let parse_fn: Rc<dyn ParseFn<C::ReturnType, FDT>> =
+            match (self.parse_fn, self.message_parse_fn) {
+                (Some(parse_fn), _) => Rc::from(parse_fn),
+                (None, Some(parse_fn)) => {
+                    let field = self
+                        .data
+                        .label()
+                        .or(self.data.name())
+                        .unwrap_or_default()
+                        .to_string();
+                    let messages = messages.clone();
+                    Rc::new(move |value| parse_fn(value).map_err(|e| messages.message(&field, &e)))
+                }
+                (None, None) => return Err(ControlBuildError::MissingParseFn),
+            };
         let unparse_fn = match self.unparse_fn {
             Some(unparse_fn) => unparse_fn,
             None => return Err(ControlBuildError::MissingUnParseFn),
         };
 
+        let in_draft = self.in_draft.unwrap_or_else(|| self.data.saved_in_draft());
+
         Ok(BuiltControlData {
-            render_data: ControlRenderData {
+            render_data: Rc::new(ControlRenderData {
                 data: self.data,
+                id,
+                required: self.required,
                 styles: self.style_attributes,
-            },
+            }),
             getter,
             setter,
             parse_fn,
-            unparse_fn,
+            unparse_fn: Rc::from(unparse_fn),
             validation_fn: self.validation_fn,
+            warning_fn: self.warning_fn,
+            validation_trigger: self.validation_trigger,
+            debounce: self.debounce,
             show_when: self.show_when,
+            in_draft,
         })
     }
 
@@ -350,6 +556,7 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
     ) -> Self {
         self.parse_fn = Some(Box::new(parse_fn));
         self.unparse_fn = Some(Box::new(unparse_fn));
+        self.message_parse_fn = None;
         self
     }
 
@@ -358,6 +565,20 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
         self.style_attributes.push(attribute);
         self
     }
+
+    /// Marks this control as required, for presentation only.
+    ///
+    /// This only tells assistive technologies that the control is required
+    /// (with `aria-required`). It does NOT add any validation, so a control
+    /// marked with this can still be submitted empty. This is unrelated to
+    /// [`ValidationBuilder::required`](crate::ValidationBuilder::required),
+    /// which is what rejects empty values; pass a validation function built
+    /// with it to [`validation_fn`](ControlBuilder::validation_fn), and call
+    /// both so the control is announced and checked as required.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
 }
 
 impl<FD, C, FDT> ControlBuilder<FD, C, FDT>
@@ -365,7 +586,7 @@ where
     FD: FormToolData,
     C: ControlData<FD>,
     FDT: TryFrom<<C as ControlData<FD>>::ReturnType>,
-    <FDT as TryFrom<<C as ControlData<FD>>::ReturnType>>::Error: ToString,
+    <FDT as TryFrom<<C as ControlData<FD>>::ReturnType>>::Error: ToString + 'static,
     <C as ControlData<FD>>::ReturnType: From<FDT>,
 {
     /// Sets the parse functions to use the [`TryFrom`] and [`From`] traits
@@ -375,8 +596,9 @@ where
     /// types in the form into what is stored in the form data struct and
     /// vice versa.
     pub fn parse_from(mut self) -> Self {
-        self.parse_fn = Some(Box::new(|control_return_value| {
-            FDT::try_from(control_return_value).map_err(|e| e.to_string())
+        self.parse_fn = None;
+        self.message_parse_fn = Some(Box::new(|control_return_value| {
+            FDT::try_from(control_return_value).map_err(parse_error_message)
         }));
         self.unparse_fn = Some(Box::new(|field| {
             <C as ControlData<FD>>::ReturnType::from(field)
@@ -405,6 +627,7 @@ where
         self.unparse_fn = Some(Box::new(|field| {
             <C as ControlData<FD>>::ReturnType::from(field)
         }));
+        self.message_parse_fn = None;
         self
     }
 }
@@ -414,7 +637,7 @@ where
     FD: FormToolData,
     C: ControlData<FD, ReturnType = String>,
     FDT: FromStr + ToString,
-    <FDT as FromStr>::Err: ToString,
+    <FDT as FromStr>::Err: ToString + 'static,
 {
     /// Sets the parse functions to use the [`FromStr`] [`ToString`] and traits
     /// for parsing and unparsing respectively. To trim the string before
@@ -424,10 +647,11 @@ where
     /// types in the form into what is stored in the form data struct and
     /// vice versa.
     pub fn parse_string(mut self) -> Self {
-        self.parse_fn = Some(Box::new(|control_return_value| {
+        self.parse_fn = None;
+        self.message_parse_fn = Some(Box::new(|control_return_value| {
             control_return_value
                 .parse::<FDT>()
-                .map_err(|e| e.to_string())
+                .map_err(parse_error_message)
         }));
         self.unparse_fn = Some(Box::new(|field| field.to_string()));
         self
@@ -442,11 +666,12 @@ where
     /// types in the form into what is stored in the form data struct and
     /// vice versa.
     pub fn parse_trimmed(mut self) -> Self {
-        self.parse_fn = Some(Box::new(|control_return_value| {
+        self.parse_fn = None;
+        self.message_parse_fn = Some(Box::new(|control_return_value| {
             control_return_value
                 .trim()
                 .parse::<FDT>()
-                .map_err(|e| e.to_string())
+                .map_err(parse_error_message)
         }));
         self.unparse_fn = Some(Box::new(|field| field.to_string()));
         self
@@ -467,6 +692,7 @@ where
                 .map_err(|_| msg.to_string())
         }));
         self.unparse_fn = Some(Box::new(|field| field.to_string()));
+        self.message_parse_fn = None;
         self
     }
 
@@ -487,6 +713,7 @@ where
                 .map_err(|_| msg.to_string())
         }));
         self.unparse_fn = Some(Box::new(|field| field.to_string()));
+        self.message_parse_fn = None;
         self
     }
 }
@@ -599,4 +826,36 @@ impl<FD: FormToolData, C: ValidatedControlData<FD>, FDT> ControlBuilder<FD, C, F
         self.validation_fn = Some(Rc::new(validation_fn));
         self
     }
+
+    /// Sets the warning function for this control.
+    ///
+    /// This works like a [`validation_fn`](Self::validation_fn), but a
+    /// failure only shows the message as a warning, and does not stop the
+    /// form from being submitted. Warnings are only checked when validation
+    /// passes.
+    ///
+    /// Ex. You want to point out that an amount is much larger than usual,
+    /// but still allow it.
+    pub fn warning_fn(mut self, warning_fn: impl Fn(&FD) -> Result<(), String> + 'static) -> Self {
+        self.warning_fn = Some(Rc::new(warning_fn));
+        self
+    }
+
+    /// Sets when this control validates its value, overriding the
+    /// form's [`validate_on`](crate::FormBuilder::validate_on).
+    pub fn validate_on(mut self, trigger: ValidationTrigger) -> Self {
+        self.validation_trigger = Some(trigger);
+        self
+    }
+
+    /// Sets whether the value of this control is saved in drafts by
+    /// [`FormBuilder::autosave`](crate::FormBuilder::autosave).
+    ///
+    /// Controls are saved by default, except for passwords. Fields that are
+    /// left out keep the value the form was built with when a draft is
+    /// restored.
+    pub fn in_draft(mut self, in_draft: bool) -> Self {
+        self.in_draft = Some(in_draft);
+        self
+    }
 }
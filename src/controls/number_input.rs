@@ -0,0 +1,608 @@
+use super::{
+    hidden::HiddenData, text_input::TextInputData, BuilderCxFn, BuilderFn, ControlBuilder,
+    ControlData, ControlRenderData, UpdateEvent, ValidatedControlData, ValidationState,
+};
+use crate::{
+    form::FormToolData, form_builder::FormBuilder, messages::ValidationMessage, styles::FormStyle,
+};
+use leptos::{CollectView, RwSignal, Signal, SignalGet, SignalSetter, View};
+use std::{rc::Rc, str::FromStr};
+
+/// How a number is shown to, and typed by, the user.
+///
+/// Numbers are kept in the form data in the format Rust uses, like
+/// `-1234.56`, and shown in this format, like `-$1,234.56` or `-1.234,56 €`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NumberFormat {
+    /// The character between each group of 3 digits, if the digits are
+    /// grouped.
+    pub grouping: Option<char>,
+    /// The character between the integer and fractional parts.
+    pub decimal: char,
+    /// The text shown before the number, like a currency symbol.
+    pub prefix: Option<String>,
+    /// The text shown after the number, like a currency symbol or unit.
+    pub suffix: Option<String>,
+    /// The number of digits after the decimal separator, if it is fixed.
+    ///
+    /// Numbers with more digits are rounded, and numbers with fewer digits
+    /// are padded with zeros when shown.
+    pub precision: Option<usize>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat {
+            grouping: Some(','),
+            decimal: '.',
+            prefix: None,
+            suffix: None,
+            precision: None,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Gets the format of numbers for a locale, given as a language tag
+    /// like `en-US` or `de`.
+    ///
+    /// This only sets the grouping and decimal separators. Locales that are
+    /// not known use the English separators.
+    pub fn locale(tag: &str) -> Self {
+        let tag = tag.replace('_', "-").to_ascii_lowercase();
+        let language = tag.split('-').next().unwrap_or_default();
+        let region = tag.split('-').nth(1).unwrap_or_default();
+
+        let (grouping, decimal) = match (language, region) {
+            ("de" | "fr" | "it", "ch" | "li") => ('\u{2019}', '.'),
+            ("es", "mx" | "us") => (',', '.'),
+            (
+                "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "ro" | "hr" | "sl"
+                | "sr" | "vi",
+                _,
+            ) => ('.', ','),
+            (
+                "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "nb" | "nn" | "no" | "fi" | "uk" | "hu"
+                | "bg" | "lt" | "lv" | "et",
+                _,
+            ) => ('\u{a0}', ','),
+            _ => (',', '.'),
+        };
+        NumberFormat {
+            grouping: Some(grouping),
+            decimal,
+            ..Default::default()
+        }
+    }
+
+    /// Turns the text the user typed into a number in the format Rust uses.
+    ///
+    /// The prefix, suffix and grouping separators are removed, and the
+    /// number is rounded to the precision. Grouping separators are only
+    /// accepted between groups of 3 digits before the decimal separator, so
+    /// `1,5` isn't read as `15`. Text that is not a number is returned
+    /// trimmed, so parsing it fails.
+    pub fn normalize(&self, text: &str) -> String {
+        let text = text.trim();
+        let mut number = text.to_string();
+        let mut negative = false;
+        for _ in 0..2 {
+            number = number.trim().to_string();
+            if let Some(rest) = number.strip_prefix('-') {
+                negative = !negative;
+                number = rest.to_string();
+            }
+            if let Some(rest) = self
+                .prefix
+                .as_deref()
+                .and_then(|p| number.strip_prefix(p.trim()))
+            {
+                number = rest.to_string();
+            }
+            if let Some(rest) = self
+                .suffix
+                .as_deref()
+                .and_then(|s| number.strip_suffix(s.trim()))
+            {
+                number = rest.to_string();
+            }
+        }
+
+        let (integer, fraction) = number.split_once(self.decimal).unwrap_or((&number, ""));
+        let Some(integer) = self.ungroup(integer) else {
+            return text.to_string();
+        };
+        let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if (integer.is_empty() && fraction.is_empty())
+            || !is_digits(&integer)
+            || !is_digits(fraction)
+        {
+            return text.to_string();
+        }
+
+        let integer = integer.trim_start_matches('0');
+        let integer = if integer.is_empty() { "0" } else { integer };
+        let (integer, fraction) = self.round(integer, fraction);
+        let sign = match negative && Self::is_nonzero(&integer, &fraction) {
+            true => "-",
+            false => "",
+        };
+        match fraction.is_empty() {
+            true => format!("{sign}{integer}"),
+            false => format!("{sign}{integer}.{fraction}"),
+        }
+    }
+
+    /// Shows a number in the format Rust uses in this format.
+    ///
+    /// Text that is not a number is returned as is, so the user can see
+    /// what they typed.
+    pub fn format(&self, number: &str) -> String {
+        let normalized = NumberFormat::default().without_grouping().normalize(number);
+        let (negative, digits) = match normalized.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, normalized.as_str()),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) {
+            return number.to_string();
+        }
+
+        let (integer, mut fraction) = self.round(integer, fraction);
+        let negative = negative && Self::is_nonzero(&integer, &fraction);
+        if let Some(precision) = self.precision {
+            fraction.extend(std::iter::repeat_n('0', precision - fraction.len()));
+        }
+
+        let mut formatted = String::new();
+        if negative {
+            formatted.push('-');
+        }
+        if let Some(ref prefix) = self.prefix {
+            formatted.push_str(prefix);
+        }
+        for (i, c) in integer.chars().enumerate() {
+            let remaining = integer.len() - i;
+            if i > 0 && remaining % 3 == 0 {
+                formatted.extend(self.grouping);
+            }
+            formatted.push(c);
+        }
+        if !fraction.is_empty() {
+            formatted.push(self.decimal);
+            formatted.push_str(&fraction);
+        }
+        if let Some(ref suffix) = self.suffix {
+            formatted.push_str(suffix);
+        }
+        formatted
+    }
+
+    /// Removes the grouping separators from the integer part of a number.
+    ///
+    /// Returns `None` if the separators are not between groups of 3 digits,
+    /// like in `1,234`.
+    fn ungroup(&self, integer: &str) -> Option<String> {
+        let is_separator = |c: char| match self.grouping {
+            Some(g) if g.is_whitespace() => c.is_whitespace(),
+            Some(g) => c == g,
+            None => false,
+        };
+        let mut groups = integer.split(is_separator);
+        let first = groups.next().unwrap_or_default();
+        let mut ungrouped = first.to_string();
+        for group in groups {
+            if first.is_empty() || first.len() > 3 || group.len() != 3 {
+                return None;
+            }
+            ungrouped.push_str(group);
+        }
+        Some(ungrouped)
+    }
+
+    /// Checks if the digits of a number are not all zero, as zero shouldn't
+    /// be shown with a minus sign.
+    fn is_nonzero(integer: &str, fraction: &str) -> bool {
+        integer.chars().chain(fraction.chars()).any(|c| c != '0')
+    }
+
+    /// Helper for getting a copy of this format with no grouping.
+    fn without_grouping(mut self) -> Self {
+        self.grouping = None;
+        self
+    }
+
+    /// Rounds the digits of a number to the precision, rounding half away
+    /// from zero.
+    fn round(&self, integer: &str, fraction: &str) -> (String, String) {
+        let Some(precision) = self.precision else {
+            return (integer.to_string(), fraction.to_string());
+        };
+        if fraction.len() <= precision {
+            return (integer.to_string(), fraction.to_string());
+        }
+
+        let mut digits: Vec<u8> = integer
+            .bytes()
+            .chain(fraction.bytes().take(precision))
+            .collect();
+        if fraction.as_bytes()[precision] >= b'5' {
+            // add one to the last digit, carrying as needed
+            let mut carry = true;
+            for digit in digits.iter_mut().rev() {
+                if *digit == b'9' {
+                    *digit = b'0';
+                } else {
+                    *digit += 1;
+                    carry = false;
+                    break;
+                }
+            }
+            if carry {
+                digits.insert(0, b'1');
+            }
+        }
+
+        let digits = String::from_utf8(digits).expect("digits to be ascii");
+        let (integer, fraction) = digits.split_at(digits.len() - precision);
+        (integer.to_string(), fraction.to_string())
+    }
+}
+
+/// Data used for the number input control.
+///
+/// This is a text input that shows its number with a [`NumberFormat`]. The
+/// control's value is the number in the format Rust uses, so it can be
+/// parsed into integers, floats and decimal types.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NumberInputData {
+    pub input: TextInputData,
+    pub format: NumberFormat,
+}
+
+impl Default for NumberInputData {
+    fn default() -> Self {
+        NumberInputData {
+            input: TextInputData {
+                input_type: "text",
+                // formatting while the user is typing would move the caret
+                update_event: UpdateEvent::OnChange,
+                ..Default::default()
+            },
+            format: NumberFormat::default(),
+        }
+    }
+}
+
+impl<FD: FormToolData> ControlData<FD> for NumberInputData {
+    type ReturnType = String;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        // the formatted text is not sent with the form, the number is sent
+        // with a hidden input instead, so the server can parse it
+        let hidden_control = ControlRenderData {
+            styles: Vec::new(),
+            id: format!("{}-value", control.id),
+            required: false,
+            data: HiddenData {
+                name: control.data.input.name.clone(),
+            },
+        };
+        let text_control = ControlRenderData {
+            styles: control.styles.clone(),
+            id: control.id.clone(),
+            required: control.required,
+            data: TextInputData {
+                name: String::new(),
+                ..control.data.input.clone()
+            },
+        };
+
+        let format = control.data.format.clone();
+        let formatted_getter = Signal::derive(move || format.format(&value_getter.get()));
+        let format = control.data.format.clone();
+        let number_setter =
+            SignalSetter::map(move |text: String| value_setter.set(format.normalize(&text)));
+
+        let hidden = match control.data.input.name.is_empty() {
+            true => None,
+            false => Some(fs.hidden(Rc::new(hidden_control), Some(value_getter))),
+        };
+        let text_input = fs.text_input(
+            Rc::new(text_control),
+            formatted_getter,
+            number_setter,
+            validation_state,
+        );
+        [Some(text_input), hidden]
+            .into_iter()
+            .flatten()
+            .collect_view()
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.input.name)
+    }
+
+    fn label(&self) -> Option<&str> {
+        self.input.label.as_deref()
+    }
+
+    fn display_value(&self, _fd: RwSignal<FD>, value: &Self::ReturnType) -> Option<String> {
+        Some(self.format.format(value))
+    }
+
+    fn value_to_string(&self, value: &Self::ReturnType) -> Option<String> {
+        Some(value.clone())
+    }
+
+    fn value_from_string(&self, value: &str) -> Option<Self::ReturnType> {
+        Some(value.to_string())
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for NumberInputData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a number input control and adds it to the form.
+    pub fn number_input<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, NumberInputData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a number input control using the form's context and adds it
+    /// to the form.
+    pub fn number_input_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, NumberInputData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, NumberInputData, FDT> {
+    /// Sets the name of the number input.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with. The number is sent in the format Rust uses, not the way it is
+    /// shown to the user.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.input.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the number input.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.input.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the placeholder for the number input.
+    pub fn placeholder(mut self, placeholder: impl ToString) -> Self {
+        self.data.input.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Sets the whole format of the number.
+    pub fn format(mut self, format: NumberFormat) -> Self {
+        self.data.format = format;
+        self
+    }
+
+    /// Sets the grouping and decimal separators to the ones used by a
+    /// locale, see [`NumberFormat::locale`].
+    pub fn locale(mut self, tag: &str) -> Self {
+        let locale = NumberFormat::locale(tag);
+        self.data.format.grouping = locale.grouping;
+        self.data.format.decimal = locale.decimal;
+        self
+    }
+
+    /// Sets the character between each group of 3 digits, or `None` to not
+    /// group the digits.
+    pub fn grouping(mut self, grouping: Option<char>) -> Self {
+        self.data.format.grouping = grouping;
+        self
+    }
+
+    /// Sets the character between the integer and fractional parts.
+    pub fn decimal_separator(mut self, decimal: char) -> Self {
+        self.data.format.decimal = decimal;
+        self
+    }
+
+    /// Sets the text shown before the number, like `$`.
+    pub fn prefix(mut self, prefix: impl ToString) -> Self {
+        self.data.format.prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Sets the text shown after the number, like ` €` or ` kg`.
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.data.format.suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// Sets the number of digits after the decimal separator.
+    ///
+    /// Numbers with more digits are rounded, so a precision of 0 makes the
+    /// number input suitable for integers.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.data.format.precision = Some(precision);
+        self
+    }
+
+    /// Sets the event that is used to update the form data.
+    ///
+    /// The number is formatted once the form data is updated, so the
+    /// default is [`UpdateEvent::OnChange`], to not format while the user
+    /// is typing.
+    pub fn update_on(mut self, event: UpdateEvent) -> Self {
+        self.data.input.update_event = event;
+        self
+    }
+}
+
+impl<FD, FDT> ControlBuilder<FD, NumberInputData, FDT>
+where
+    FD: FormToolData,
+    FDT: FromStr + ToString,
+{
+    /// Sets the parse functions to parse the number with the [`FromStr`]
+    /// and [`ToString`] traits.
+    ///
+    /// This works for integers, floats and decimal types that parse the
+    /// format Rust uses, like `-1234.56`. The grouping and decimal
+    /// separators, prefix and suffix are removed before parsing.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_number(mut self) -> Self {
+        self.parse_fn = None;
+        self.message_parse_fn = Some(Box::new(|number: String| {
+            number
+                .parse::<FDT>()
+                .map_err(|_| ValidationMessage::NotANumber)
+        }));
+        self.unparse_fn = Some(Box::new(|field| field.to_string()));
+        self
+    }
+}
+
+impl<FD, FDT> ControlBuilder<FD, NumberInputData, Option<FDT>>
+where
+    FD: FormToolData,
+    FDT: FromStr + ToString,
+{
+    /// Sets the parse functions to parse an optional number with the
+    /// [`FromStr`] and [`ToString`] traits.
+    ///
+    /// An empty number input gives `None`. Unlike
+    /// [`parse_optional`](Self::parse_optional), text that is not a number
+    /// is a parse error, rather than `None`.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_optional_number(mut self) -> Self {
+        self.parse_fn = None;
+        self.message_parse_fn = Some(Box::new(|number: String| {
+            if number.is_empty() {
+                return Ok(None);
+            }
+            number
+                .parse::<FDT>()
+                .map(Some)
+                .map_err(|_| ValidationMessage::NotANumber)
+        }));
+        self.unparse_fn = Some(Box::new(|field| {
+            field.map(|v| v.to_string()).unwrap_or_default()
+        }));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn currency() -> NumberFormat {
+        NumberFormat {
+            prefix: Some(String::from("$")),
+            precision: Some(2),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn normalize_removes_grouping() {
+        let format = NumberFormat::default();
+        assert_eq!(format.normalize("1,234.56"), "1234.56");
+        assert_eq!(format.normalize("1,234,567"), "1234567");
+        assert_eq!(format.normalize(" 007 "), "7");
+        assert_eq!(format.normalize(".5"), "0.5");
+        assert_eq!(format.normalize("-0"), "0");
+
+        let de = NumberFormat::locale("de-DE");
+        assert_eq!(de.normalize("-1.234,5"), "-1234.5");
+
+        let fr = NumberFormat::locale("fr");
+        assert_eq!(fr.normalize("1 234,5"), "1234.5");
+        assert_eq!(fr.normalize("1\u{a0}234"), "1234");
+    }
+
+    #[test]
+    fn normalize_rejects_misplaced_grouping() {
+        let format = NumberFormat::default();
+        assert_eq!(format.normalize("1,5"), "1,5");
+        assert_eq!(format.normalize("12,34"), "12,34");
+        assert_eq!(format.normalize("1234,567"), "1234,567");
+        assert_eq!(format.normalize(",123"), ",123");
+        assert_eq!(format.normalize("1,,234"), "1,,234");
+        assert_eq!(format.normalize("1.234,5"), "1.234,5");
+        assert_eq!(format.normalize("abc"), "abc");
+        assert_eq!(format.normalize(" "), "");
+    }
+
+    #[test]
+    fn normalize_strips_prefix_and_rounds() {
+        let format = currency();
+        assert_eq!(format.normalize("$1,234.567"), "1234.57");
+        assert_eq!(format.normalize("-$1,234.5"), "-1234.5");
+        assert_eq!(format.normalize("$-5"), "-5");
+        assert_eq!(format.normalize("-$0.001"), "0.00");
+    }
+
+    #[test]
+    fn format_groups_and_pads() {
+        assert_eq!(NumberFormat::default().format("-1234.5"), "-1,234.5");
+        assert_eq!(NumberFormat::default().format("123"), "123");
+        assert_eq!(currency().format("-1234.5"), "-$1,234.50");
+        assert_eq!(currency().format("999.999"), "$1,000.00");
+        assert_eq!(currency().format("-0.001"), "$0.00");
+
+        let euro = NumberFormat {
+            suffix: Some(String::from(" \u{20ac}")),
+            ..NumberFormat::locale("de")
+        };
+        assert_eq!(euro.format("-1234.56"), "-1.234,56 \u{20ac}");
+    }
+
+    #[test]
+    fn format_keeps_text_that_is_not_a_number() {
+        assert_eq!(NumberFormat::default().format("abc"), "abc");
+        assert_eq!(NumberFormat::default().format("1,5"), "1,5");
+        assert_eq!(NumberFormat::default().format(""), "");
+    }
+
+    #[test]
+    fn round_half_away_from_zero() {
+        let format = currency();
+        let round = |integer, fraction| format.round(integer, fraction);
+        assert_eq!(round("1", "234"), (String::from("1"), String::from("23")));
+        assert_eq!(round("1", "235"), (String::from("1"), String::from("24")));
+        assert_eq!(round("0", "995"), (String::from("1"), String::from("00")));
+        assert_eq!(
+            round("99", "999"),
+            (String::from("100"), String::from("00"))
+        );
+        assert_eq!(round("1", "2"), (String::from("1"), String::from("2")));
+
+        let unrounded = NumberFormat::default();
+        assert_eq!(
+            unrounded.round("1", "23456"),
+            (String::from("1"), String::from("23456"))
+        );
+    }
+}
@@ -19,6 +19,10 @@ impl<FD: FormToolData> VanityControlData<FD> for OutputData {
     ) -> View {
         fs.output(control, value_getter)
     }
+
+    fn shown_in_review() -> bool {
+        true
+    }
 }
 impl<FD: FormToolData> GetterVanityControlData<FD> for OutputData {}
 
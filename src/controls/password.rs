@@ -0,0 +1,391 @@
+use super::{
+    text_input::password_mask, BuilderCxFn, BuilderFn, ControlBuilder, ControlData,
+    ControlRenderData, ValidatedControlData, ValidationState,
+};
+use crate::{
+    form::FormToolData, form_builder::FormBuilder, messages::ValidationMessage, styles::FormStyle,
+    validation_builder::CharacterClass,
+};
+use leptos::{RwSignal, Signal, SignalSetter, View};
+use std::rc::Rc;
+
+/// How strong a password is, as estimated by a [`PasswordData`]'s strength
+/// estimator.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PasswordStrength {
+    /// The score of the password, from 0 (very weak) to
+    /// [`MAX_SCORE`](Self::MAX_SCORE) (strong).
+    pub score: u8,
+    /// The text describing the strength to the user.
+    pub label: String,
+}
+
+impl PasswordStrength {
+    /// The highest score a password can have.
+    pub const MAX_SCORE: u8 = 4;
+
+    /// Creates a [`PasswordStrength`], capping the score at
+    /// [`MAX_SCORE`](Self::MAX_SCORE).
+    pub fn new(score: u8, label: impl ToString) -> Self {
+        PasswordStrength {
+            score: score.min(Self::MAX_SCORE),
+            label: label.to_string(),
+        }
+    }
+
+    /// Estimates the strength of a password from its length and the
+    /// [`CharacterClass`]es it uses.
+    ///
+    /// This is the estimator used by
+    /// [`strength_meter`](ControlBuilder::strength_meter). It is a simple
+    /// heuristic; for a better estimate, plug in a library like zxcvbn with
+    /// [`strength_estimator`](ControlBuilder::strength_estimator).
+    pub fn estimate(password: &str) -> Self {
+        let len = password.chars().count();
+        let classes = CharacterClass::count_in(password);
+        let score = match len {
+            0 => 0,
+            1..=7 => 1.min(classes as u8),
+            _ => {
+                let length_score = match len {
+                    8..=11 => 1,
+                    12..=15 => 2,
+                    _ => 3,
+                };
+                let class_score = match classes {
+                    0..=1 => 0,
+                    2..=3 => 1,
+                    _ => 2,
+                };
+                length_score + class_score
+            }
+        };
+        let label = match score {
+            0 => "Very weak",
+            1 => "Weak",
+            2 => "Fair",
+            3 => "Good",
+            _ => "Strong",
+        };
+        PasswordStrength::new(score, label)
+    }
+
+    /// Gets how full the strength meter should be, as a percentage.
+    ///
+    /// Even the weakest password fills some of the meter, so the meter is
+    /// visible.
+    pub fn percent(&self) -> u8 {
+        let score = u16::from(self.score.min(Self::MAX_SCORE));
+        ((score + 1) * 100 / (u16::from(Self::MAX_SCORE) + 1)) as u8
+    }
+}
+
+/// A function that estimates the strength of a password.
+pub trait StrengthFn: Fn(&str) -> PasswordStrength + 'static {}
+impl<F> StrengthFn for F where F: Fn(&str) -> PasswordStrength + 'static {}
+
+/// Data used for the password control.
+#[derive(Clone)]
+pub struct PasswordData {
+    pub name: String,
+    pub label: Option<String>,
+    pub placeholder: Option<String>,
+    /// The value of the input's "autocomplete" attribute, which tells
+    /// password managers whether to fill in a saved password or suggest a
+    /// new one.
+    pub autocomplete: &'static str,
+    /// The text of the toggle button for showing and hiding the password,
+    /// if it can be shown.
+    pub reveal_labels: Option<(String, String)>,
+    /// The function estimating the password's strength, if the strength
+    /// meter is shown.
+    pub strength: Option<Rc<dyn StrengthFn>>,
+}
+
+impl Default for PasswordData {
+    fn default() -> Self {
+        PasswordData {
+            name: String::new(),
+            label: None,
+            placeholder: None,
+            autocomplete: "current-password",
+            reveal_labels: Some((String::from("Show"), String::from("Hide"))),
+            strength: None,
+        }
+    }
+}
+
+impl PasswordData {
+    /// Gets the text of the toggle button, given if the password is shown.
+    pub fn reveal_label(&self, shown: bool) -> Option<&str> {
+        let (show, hide) = self.reveal_labels.as_ref()?;
+        match shown {
+            true => Some(hide),
+            false => Some(show),
+        }
+    }
+
+    /// Estimates the strength of the password, if the strength meter is
+    /// shown.
+    ///
+    /// Nothing is estimated for an empty password.
+    pub fn strength(&self, password: &str) -> Option<PasswordStrength> {
+        if password.is_empty() {
+            return None;
+        }
+        self.strength.as_ref().map(|strength| strength(password))
+    }
+}
+
+impl<FD: FormToolData> ControlData<FD> for PasswordData {
+    type ReturnType = String;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        fs.password(control, value_getter, value_setter, validation_state)
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    // passwords are never shown, and are kept out of the query string by
+    // not having a string value
+    fn display_value(&self, _fd: RwSignal<FD>, value: &Self::ReturnType) -> Option<String> {
+        Some(password_mask(value))
+    }
+
+    // passwords would be stored in plain text
+    fn saved_in_draft(&self) -> bool {
+        false
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for PasswordData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a password control and adds it to the form.
+    pub fn password<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, PasswordData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a password control using the form's context and adds it to the
+    /// form.
+    pub fn password_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, PasswordData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+
+    /// Builds a control for confirming a password and adds it to the form.
+    ///
+    /// This is a password control for a new password, that must match the
+    /// password returned by `password`. `password_name` is the name of the
+    /// password field, for error messages. The validation function set in
+    /// the `builder` runs first, if there is one.
+    pub fn confirm_password(
+        mut self,
+        password: impl Fn(&FD) -> &str + 'static,
+        password_name: impl ToString,
+        builder: impl BuilderFn<ControlBuilder<FD, PasswordData, String>>,
+    ) -> Self {
+        let control_builder = ControlBuilder::new(PasswordData {
+            autocomplete: "new-password",
+            ..Default::default()
+        });
+        let mut control = builder(control_builder);
+
+        let field_name = control
+            .data
+            .label
+            .clone()
+            .unwrap_or(control.data.name.clone());
+        let password_name = password_name.to_string();
+        let messages = self.messages.clone();
+        let getter = control.getter.clone();
+        let validation_fn = control.validation_fn.take();
+        control.validation_fn = Some(Rc::new(move |fd: &FD| {
+            if let Some(ref validation_fn) = validation_fn {
+                validation_fn(fd)?;
+            }
+            let Some(ref getter) = getter else {
+                return Ok(());
+            };
+            if getter(fd) != password(fd) {
+                let message = ValidationMessage::EqualsField(password_name.clone());
+                return Err(messages.message(&field_name, &message));
+            }
+            Ok(())
+        }));
+
+        self.add_control(control);
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, PasswordData, FDT> {
+    /// Sets the name of the password control.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the password control.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the placeholder for the password control.
+    pub fn placeholder(mut self, placeholder: impl ToString) -> Self {
+        self.data.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Marks this as a field for a new password, rather than for logging in.
+    ///
+    /// This lets password managers suggest a new password, instead of
+    /// filling in a saved one.
+    pub fn new_password(mut self) -> Self {
+        self.data.autocomplete = "new-password";
+        self
+    }
+
+    /// Sets the text of the toggle button for showing and hiding the
+    /// password. These default to "Show" and "Hide".
+    pub fn reveal_labels(mut self, show: impl ToString, hide: impl ToString) -> Self {
+        self.data.reveal_labels = Some((show.to_string(), hide.to_string()));
+        self
+    }
+
+    /// Removes the toggle button for showing and hiding the password.
+    pub fn no_reveal(mut self) -> Self {
+        self.data.reveal_labels = None;
+        self
+    }
+
+    /// Shows a meter of the password's strength, estimated with
+    /// [`PasswordStrength::estimate`].
+    ///
+    /// The meter is only a hint to the user, use the
+    /// [`ValidationBuilder`](crate::ValidationBuilder) rules like
+    /// [`min_len`](crate::ValidationBuilder::min_len) and
+    /// [`min_classes`](crate::ValidationBuilder::min_classes) to require a
+    /// strong password.
+    pub fn strength_meter(mut self) -> Self {
+        self.data.strength = Some(Rc::new(PasswordStrength::estimate));
+        self
+    }
+
+    /// Shows a meter of the password's strength, estimated by the given
+    /// function.
+    pub fn strength_estimator(mut self, estimator: impl StrengthFn) -> Self {
+        self.data.strength = Some(Rc::new(estimator));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::GridFormStyle;
+
+    #[derive(Clone, Default)]
+    struct SignUp {
+        password: String,
+        confirm: String,
+    }
+
+    impl FormToolData for SignUp {
+        type Style = GridFormStyle;
+        type Context = ();
+
+        fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+            fb.confirm_password(
+                |fd| &fd.password,
+                "Password",
+                |c| {
+                    c.named("confirm")
+                        .labeled("Confirm Password")
+                        .getter(|fd| fd.confirm.clone())
+                        .setter(|fd, value| fd.confirm = value)
+                        .parse_string()
+                        .validation_fn(|fd| match fd.confirm.is_empty() {
+                            true => Err(String::from("Confirm Password is required")),
+                            false => Ok(()),
+                        })
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn estimate_scores() {
+        let score = |password| PasswordStrength::estimate(password).score;
+        assert_eq!(score(""), 0);
+        assert_eq!(score("abc"), 1);
+        assert_eq!(score("aB3!"), 1);
+        assert_eq!(score("abcdefgh"), 1);
+        assert_eq!(score("abcdefg1"), 2);
+        assert_eq!(score("abcdefghijkl"), 2);
+        assert_eq!(score("abcdefghijklmnop"), 3);
+        assert_eq!(score("abcdefgH1!"), 3);
+        assert_eq!(score("abcdefghijkL1!"), 4);
+        assert_eq!(score("abcdefghijklmnoP1!"), 4);
+    }
+
+    #[test]
+    fn estimate_labels() {
+        assert_eq!(PasswordStrength::estimate("").label, "Very weak");
+        assert_eq!(PasswordStrength::estimate("abc").label, "Weak");
+        assert_eq!(PasswordStrength::estimate("abcdefg1").label, "Fair");
+        assert_eq!(PasswordStrength::estimate("abcdefgH1!").label, "Good");
+        assert_eq!(PasswordStrength::estimate("abcdefghijkL1!").label, "Strong");
+    }
+
+    #[test]
+    fn percent() {
+        assert_eq!(PasswordStrength::new(0, "").percent(), 20);
+        assert_eq!(PasswordStrength::new(2, "").percent(), 60);
+        assert_eq!(PasswordStrength::new(4, "").percent(), 100);
+        assert_eq!(
+            PasswordStrength::new(9, "").score,
+            PasswordStrength::MAX_SCORE
+        );
+    }
+
+    #[test]
+    fn confirm_mismatch() {
+        let fd = |password: &str, confirm: &str| SignUp {
+            password: password.to_string(),
+            confirm: confirm.to_string(),
+        };
+        assert_eq!(fd("hunter2", "hunter2").validate(()), Ok(()));
+        assert_eq!(
+            fd("hunter2", "hunter3").validate(()),
+            Err(String::from("Confirm Password must match Password"))
+        );
+        // the builder's validation function runs first
+        assert_eq!(
+            fd("hunter2", "").validate(()),
+            Err(String::from("Confirm Password is required"))
+        );
+    }
+}
@@ -30,6 +30,27 @@ impl<FD: FormToolData> ControlData<FD> for RadioButtonsData {
     ) -> View {
         fs.radio_buttons(control, value_getter, value_setter, validation_state)
     }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    fn display_value(&self, _fd: RwSignal<FD>, value: &Self::ReturnType) -> Option<String> {
+        let option = self.options.iter().find(|(_, v)| v == value);
+        Some(option.map(|(display, _)| display).unwrap_or(value).clone())
+    }
+
+    fn value_to_string(&self, value: &Self::ReturnType) -> Option<String> {
+        Some(value.clone())
+    }
+
+    fn value_from_string(&self, value: &str) -> Option<Self::ReturnType> {
+        Some(value.to_string())
+    }
 }
 impl<FD: FormToolData> ValidatedControlData<FD> for RadioButtonsData {}
 
@@ -82,6 +82,8 @@ impl<FD: FormToolData> ControlData<FD> for SelectBuildData<FD> {
 
         let new_control = ControlRenderData {
             styles: control.styles.clone(),
+            id: control.id.clone(),
+            required: control.required,
             data: SelectData {
                 name: control.data.name.clone(),
                 label: control.data.label.clone(),
@@ -93,6 +95,36 @@ impl<FD: FormToolData> ControlData<FD> for SelectBuildData<FD> {
 
         fs.select(new_control, value_getter, value_setter, validation_state)
     }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    fn display_value(&self, fd: RwSignal<FD>, value: &Self::ReturnType) -> Option<String> {
+        if value.is_empty() {
+            if let Some(ref blank_option) = self.blank_option {
+                return Some(blank_option.clone());
+            }
+        }
+        let options = match self.dynamic_options {
+            Some(ref dynamic_options) => dynamic_options(fd),
+            None => self.options.get(),
+        };
+        let option = options.into_iter().find(|(_, v)| v == value);
+        Some(option.map(|(display, _)| display).unwrap_or(value.clone()))
+    }
+
+    fn value_to_string(&self, value: &Self::ReturnType) -> Option<String> {
+        Some(value.clone())
+    }
+
+    fn value_from_string(&self, value: &str) -> Option<Self::ReturnType> {
+        Some(value.to_string())
+    }
 }
 impl<FD: FormToolData> ValidatedControlData<FD> for SelectBuildData<FD> {}
 
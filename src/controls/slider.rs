@@ -29,6 +29,22 @@ impl<FD: FormToolData> ControlData<FD> for SliderData {
     ) -> View {
         fs.slider(control, value_getter, value_setter, validation_state)
     }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    fn value_to_string(&self, value: &Self::ReturnType) -> Option<String> {
+        Some(value.clone())
+    }
+
+    fn value_from_string(&self, value: &str) -> Option<Self::ReturnType> {
+        Some(value.to_string())
+    }
 }
 
 impl<FD: FormToolData> FormBuilder<FD> {
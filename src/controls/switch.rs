@@ -0,0 +1,134 @@
+use super::{
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
+    ValidationState,
+};
+use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
+use leptos::{RwSignal, Signal, SignalSetter, View};
+use std::rc::Rc;
+
+/// Data used for the switch control.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SwitchData {
+    pub name: String,
+    pub label: Option<String>,
+    /// The text shown next to the switch while it is on.
+    pub on_label: Option<String>,
+    /// The text shown next to the switch while it is off.
+    pub off_label: Option<String>,
+    pub disabled: bool,
+}
+
+impl SwitchData {
+    /// Gets the text to show next to the switch for the given state, if
+    /// there is one.
+    pub fn state_label(&self, on: bool) -> Option<&str> {
+        match on {
+            true => self.on_label.as_deref(),
+            false => self.off_label.as_deref(),
+        }
+    }
+}
+
+impl<FD: FormToolData> ControlData<FD> for SwitchData {
+    type ReturnType = bool;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        fs.switch(control, value_getter, value_setter, validation_state)
+    }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    fn display_value(&self, _fd: RwSignal<FD>, value: &Self::ReturnType) -> Option<String> {
+        let default = match value {
+            true => "On",
+            false => "Off",
+        };
+        Some(self.state_label(*value).unwrap_or(default).to_string())
+    }
+
+    fn value_to_string(&self, value: &Self::ReturnType) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    fn value_from_string(&self, value: &str) -> Option<Self::ReturnType> {
+        match value {
+            "true" | "checked" | "on" => Some(true),
+            "false" | "" => Some(false),
+            _ => None,
+        }
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for SwitchData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a switch and adds it to the form.
+    pub fn switch<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, SwitchData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a switch using the form's context and adds it to the form.
+    pub fn switch_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, SwitchData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, SwitchData, FDT> {
+    /// Sets the name of the switch.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    ///
+    /// Like checkboxes, the value "on" is sent while the switch is on, and
+    /// no key value pair is sent while it is off.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the text of the switch's label.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the text shown next to the switch while it is on.
+    pub fn on_label(mut self, on_label: impl ToString) -> Self {
+        self.data.on_label = Some(on_label.to_string());
+        self
+    }
+
+    /// Sets the text shown next to the switch while it is off.
+    pub fn off_label(mut self, off_label: impl ToString) -> Self {
+        self.data.off_label = Some(off_label.to_string());
+        self
+    }
+
+    /// Disables the switch, so the user can't change it.
+    ///
+    /// Like all disabled inputs, the switch's value is not sent when the
+    /// form is submitted.
+    pub fn disabled(mut self) -> Self {
+        self.data.disabled = true;
+        self
+    }
+}
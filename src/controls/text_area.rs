@@ -4,7 +4,7 @@ use super::{
 };
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
 use leptos::{RwSignal, Signal, SignalSetter, View};
-use std::rc::Rc;
+use std::{rc::Rc, time::Duration};
 
 /// Data used for the text area control.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -28,6 +28,22 @@ impl<FD: FormToolData> ControlData<FD> for TextAreaData {
     ) -> View {
         fs.text_area(control, value_getter, value_setter, validation_state)
     }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    fn value_to_string(&self, value: &Self::ReturnType) -> Option<String> {
+        Some(value.clone())
+    }
+
+    fn value_from_string(&self, value: &str) -> Option<Self::ReturnType> {
+        Some(value.to_string())
+    }
 }
 impl<FD: FormToolData> ValidatedControlData<FD> for TextAreaData {}
 
@@ -74,8 +90,25 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, TextAreaData, FDT> {
     }
 
     /// Sets the event that is used to update the form data.
+    ///
+    /// This replaces the [`UpdateEvent::OnInput`] set by
+    /// [`debounce`](Self::debounce), which leaves the debounce with nothing
+    /// to do, so don't combine the two.
     pub fn update_on(mut self, event: UpdateEvent) -> Self {
         self.data.update_event = event;
         self
     }
+
+    /// Debounces the updates to the form data, so the form data is only
+    /// updated once the user stops typing for `delay`.
+    ///
+    /// This updates on [`UpdateEvent::OnInput`], replacing any event set
+    /// with [`update_on`](Self::update_on), as the other events already
+    /// update rarely. Any pending input is written to the form data when the
+    /// text area loses focus and when the form is submitted.
+    pub fn debounce(mut self, delay: Duration) -> Self {
+        self.data.update_event = UpdateEvent::OnInput;
+        self.debounce = Some(delay);
+        self
+    }
 }
@@ -4,7 +4,7 @@ use super::{
 };
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
 use leptos::{RwSignal, Signal, SignalSetter, View};
-use std::rc::Rc;
+use std::{rc::Rc, time::Duration};
 
 /// Data used for the text input control.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -28,6 +28,26 @@ impl Default for TextInputData {
     }
 }
 
+impl TextInputData {
+    /// Whether this is a password input, made with
+    /// [`password`](ControlBuilder::password).
+    fn is_password(&self) -> bool {
+        self.input_type == "password"
+    }
+}
+
+/// Masks a password for showing it to the user, like when reviewing the
+/// form.
+///
+/// The mask has a fixed width, so it doesn't reveal the password's length.
+/// Empty passwords stay empty.
+pub(crate) fn password_mask(value: &str) -> String {
+    match value.is_empty() {
+        true => String::new(),
+        false => "\u{2022}".repeat(8),
+    }
+}
+
 impl<FD: FormToolData> ControlData<FD> for TextInputData {
     type ReturnType = String;
 
@@ -41,6 +61,36 @@ impl<FD: FormToolData> ControlData<FD> for TextInputData {
     ) -> View {
         fs.text_input(control, value_getter, value_setter, validation_state)
     }
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    // passwords are kept out of the query string by not having a string
+    // value
+    fn value_to_string(&self, value: &Self::ReturnType) -> Option<String> {
+        (!self.is_password()).then(|| value.clone())
+    }
+
+    fn value_from_string(&self, value: &str) -> Option<Self::ReturnType> {
+        (!self.is_password()).then(|| value.to_string())
+    }
+
+    fn display_value(&self, _fd: RwSignal<FD>, value: &Self::ReturnType) -> Option<String> {
+        match self.is_password() {
+            true => Some(password_mask(value)),
+            false => Some(value.clone()),
+        }
+    }
+
+    // passwords would be stored in plain text
+    fn saved_in_draft(&self) -> bool {
+        !self.is_password()
+    }
 }
 impl<FD: FormToolData> ValidatedControlData<FD> for TextInputData {}
 
@@ -105,8 +155,103 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, TextInputData, FDT> {
     }
 
     /// Sets the event that is used to update the form data.
+    ///
+    /// This replaces the [`UpdateEvent::OnInput`] set by
+    /// [`debounce`](Self::debounce), which leaves the debounce with nothing
+    /// to do, so don't combine the two.
     pub fn update_on(mut self, event: UpdateEvent) -> Self {
         self.data.update_event = event;
         self
     }
+
+    /// Debounces the updates to the form data, so the form data is only
+    /// updated once the user stops typing for `delay`.
+    ///
+    /// This updates on [`UpdateEvent::OnInput`], replacing any event set
+    /// with [`update_on`](Self::update_on), as the other events already
+    /// update rarely. Any pending input is written to the form data when the
+    /// text input loses focus and when the form is submitted.
+    pub fn debounce(mut self, delay: Duration) -> Self {
+        self.data.update_event = UpdateEvent::OnInput;
+        self.debounce = Some(delay);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        messages::{DefaultMessages, MessageCatalog},
+        styles::GridFormStyle,
+    };
+
+    #[derive(Clone, Default)]
+    struct Login {
+        username: String,
+        password: String,
+    }
+
+    impl FormToolData for Login {
+        type Style = GridFormStyle;
+        type Context = ();
+
+        fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+            fb
+        }
+    }
+
+    fn in_draft(builder: impl BuilderFn<ControlBuilder<Login, TextInputData, String>>) -> bool {
+        let messages: Rc<dyn MessageCatalog> = Rc::new(DefaultMessages);
+        let control = builder(ControlBuilder::new(TextInputData::default()))
+            .parse_string()
+            .build(String::from("id"), &messages)
+            .unwrap_or_else(|e| panic!("{}", e));
+        control.in_draft
+    }
+
+    #[test]
+    fn password_left_out_of_draft() {
+        assert!(in_draft(|c| c
+            .named("username")
+            .getter(|fd| fd.username.clone())
+            .setter(|fd, value| fd.username = value)));
+        assert!(!in_draft(|c| c
+            .named("password")
+            .password()
+            .getter(|fd| fd.password.clone())
+            .setter(|fd, value| fd.password = value)));
+        assert!(in_draft(|c| c
+            .named("password")
+            .password()
+            .in_draft(true)
+            .getter(|fd| fd.password.clone())
+            .setter(|fd, value| fd.password = value)));
+    }
+
+    #[test]
+    fn password_value_hidden() {
+        let text = TextInputData::default();
+        let password = TextInputData {
+            input_type: "password",
+            ..TextInputData::default()
+        };
+        let value = String::from("hunter2");
+
+        assert_eq!(
+            ControlData::<Login>::value_to_string(&text, &value),
+            Some(value.clone())
+        );
+        assert_eq!(
+            ControlData::<Login>::value_to_string(&password, &value),
+            None
+        );
+        assert_eq!(
+            ControlData::<Login>::value_from_string(&password, "hunter2"),
+            None
+        );
+        assert_eq!(password_mask(&value), "\u{2022}".repeat(8));
+        assert_eq!(password_mask("a"), password_mask("a much longer password"));
+        assert_eq!(password_mask(""), "");
+    }
 }
@@ -0,0 +1,225 @@
+use crate::{form::FormToolData, form_builder::FormBuilder};
+use leptos::*;
+use std::rc::Rc;
+
+/// A function that sets a derived field of the form data.
+pub(crate) trait DeriveFn<FD>: Fn(&mut FD) + 'static {}
+impl<FD, F> DeriveFn<FD> for F where F: Fn(&mut FD) + 'static {}
+
+/// A rule that keeps a field of the form data computed from other fields.
+pub(crate) struct Derivation<FD: 'static> {
+    /// Sets the field to its derived value, if it should be.
+    pub(crate) apply: Rc<dyn DeriveFn<FD>>,
+    /// Keeps the field up to date in the form data signal.
+    pub(crate) install: Rc<dyn Fn(RwSignal<FD>)>,
+}
+
+impl<FD: 'static> Clone for Derivation<FD> {
+    fn clone(&self) -> Self {
+        Derivation {
+            apply: self.apply.clone(),
+            install: self.install.clone(),
+        }
+    }
+}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Keeps a field of the form data computed from other fields.
+    ///
+    /// Whenever the value returned by `compute` changes, it is written to
+    /// the field with the `setter`. The `getter` is used to skip writing a
+    /// value the field already has. The field is also derived by
+    /// [`FormValidator::validate`](crate::FormValidator::validate) before
+    /// validating, so the server doesn't need to trust the value sent by the
+    /// client.
+    ///
+    /// This is useful for things like `total = qty * price`.
+    pub fn derive<T: Clone + PartialEq + 'static>(
+        mut self,
+        getter: impl Fn(&FD) -> T + 'static,
+        setter: impl Fn(&mut FD, T) + 'static,
+        compute: impl Fn(&FD) -> T + 'static,
+    ) -> Self {
+        let getter = Rc::new(getter);
+        let setter = Rc::new(setter);
+        let compute = Rc::new(compute);
+
+        let (apply_getter, apply_setter, apply_compute) =
+            (getter.clone(), setter.clone(), compute.clone());
+        let apply = move |fd: &mut FD| {
+            let value = apply_compute(fd);
+            if apply_getter(fd) != value {
+                apply_setter(fd, value);
+            }
+        };
+
+        let install = move |fd: RwSignal<FD>| {
+            let (getter, setter, compute) = (getter.clone(), setter.clone(), compute.clone());
+            // memoized so the field is only written when the inputs change
+            // the derived value, not whenever the form data changes
+            let derived = create_memo(move |_| fd.with(|fd| compute(fd)));
+            create_effect(move |_| {
+                let value = derived.get();
+                if fd.with_untracked(|fd| getter(fd)) != value {
+                    fd.update(|fd| setter(fd, value));
+                }
+            });
+        };
+
+        self.derivations.push(Derivation {
+            apply: Rc::new(apply),
+            install: Rc::new(install),
+        });
+        self
+    }
+
+    /// Keeps a field of the form data computed from other fields, until the
+    /// user overrides it.
+    ///
+    /// This works like [`derive`](Self::derive), but the field stops being
+    /// derived when it holds a value other than the last derived value. It
+    /// starts being derived again if it is cleared back to its default
+    /// value. The [`FormValidator`](crate::FormValidator) only derives the
+    /// field if it holds the default value, as it can't know what the user
+    /// typed.
+    ///
+    /// This is useful for things like a slug that is derived from the title,
+    /// but can be edited.
+    pub fn derive_until_edited<T: Clone + PartialEq + Default + 'static>(
+        mut self,
+        getter: impl Fn(&FD) -> T + 'static,
+        setter: impl Fn(&mut FD, T) + 'static,
+        compute: impl Fn(&FD) -> T + 'static,
+    ) -> Self {
+        let getter = Rc::new(getter);
+        let setter = Rc::new(setter);
+        let compute = Rc::new(compute);
+
+        let (apply_getter, apply_setter, apply_compute) =
+            (getter.clone(), setter.clone(), compute.clone());
+        let apply = move |fd: &mut FD| {
+            if apply_getter(fd) == T::default() {
+                let value = apply_compute(fd);
+                apply_setter(fd, value);
+            }
+        };
+
+        let install = move |fd: RwSignal<FD>| {
+            let (getter, setter, compute) = (getter.clone(), setter.clone(), compute.clone());
+            let derived = create_memo(move |_| fd.with(|fd| compute(fd)));
+            // the effect returns the value the field was last derived to, or
+            // `None` if the user has overridden it
+            create_effect(move |last_derived: Option<Option<T>>| {
+                let value = derived.get();
+                let current = fd.with_untracked(|fd| getter(fd));
+                if current == value {
+                    return Some(value);
+                }
+                let following =
+                    current == T::default() || Some(&current) == last_derived.flatten().as_ref();
+                if !following {
+                    return None;
+                }
+                fd.update(|fd| setter(fd, value.clone()));
+                Some(value)
+            });
+        };
+
+        self.derivations.push(Derivation {
+            apply: Rc::new(apply),
+            install: Rc::new(install),
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{styles::GridFormStyle, FormBuilder, FormToolData};
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Order {
+        qty: u32,
+        price: u32,
+        total: u32,
+        title: String,
+        slug: String,
+    }
+
+    impl FormToolData for Order {
+        type Style = GridFormStyle;
+        type Context = ();
+
+        fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+            fb.derive(
+                |fd| fd.total,
+                |fd, total| fd.total = total,
+                |fd| fd.qty * fd.price,
+            )
+            .derive_until_edited(
+                |fd| fd.slug.clone(),
+                |fd, slug| fd.slug = slug,
+                |fd| fd.title.to_lowercase().replace(' ', "-"),
+            )
+            .text_input(|c| {
+                c.named("total")
+                    .getter(|fd| fd.total)
+                    .setter(|fd, total| fd.total = total)
+                    .parse_string()
+                    .validation_fn(|fd| match fd.total > 100 {
+                        true => Err(String::from("Total must be <= 100")),
+                        false => Ok(()),
+                    })
+            })
+        }
+    }
+
+    fn order(qty: u32, price: u32, total: u32, title: &str, slug: &str) -> Order {
+        Order {
+            qty,
+            price,
+            total,
+            title: title.to_string(),
+            slug: slug.to_string(),
+        }
+    }
+
+    #[test]
+    fn derive_recomputes() {
+        let validator = Order::get_validator(());
+        let mut fd = order(3, 4, 999, "", "");
+        validator.derive(&mut fd);
+        assert_eq!(fd.total, 12);
+    }
+
+    #[test]
+    fn derive_until_edited_keeps_edits() {
+        let validator = Order::get_validator(());
+
+        let mut fd = order(0, 0, 0, "Hello World", "");
+        validator.derive(&mut fd);
+        assert_eq!(fd.slug, "hello-world");
+
+        let mut fd = order(0, 0, 0, "Hello World", "my-slug");
+        validator.derive(&mut fd);
+        assert_eq!(fd.slug, "my-slug");
+    }
+
+    #[test]
+    fn validate_uses_derived_values() {
+        // the client claims a small total, but the derived total is too big
+        let fd = order(20, 10, 1, "", "");
+        assert_eq!(fd.validate(()), Err(String::from("Total must be <= 100")));
+        assert_eq!(fd.total, 1);
+
+        let fd = order(2, 10, 999, "", "");
+        assert_eq!(fd.validate(()), Ok(()));
+    }
+
+    #[test]
+    fn validate_and_derive_writes_back() {
+        let mut fd = order(2, 10, 999, "A Title", "");
+        assert_eq!(fd.validate_and_derive(()), Ok(()));
+        assert_eq!(fd, order(2, 10, 20, "A Title", "a-title"));
+    }
+}
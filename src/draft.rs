@@ -0,0 +1,237 @@
+use crate::{form::FormToolData, form_builder::FormBuilder};
+use leptos::*;
+use leptos_dom::helpers::TimeoutHandle;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+/// The browser storage that a form's draft is saved to.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DraftStorage {
+    /// `localStorage`, which keeps the draft until it is discarded.
+    #[default]
+    Local,
+    /// `sessionStorage`, which keeps the draft until the tab is closed.
+    Session,
+}
+
+impl DraftStorage {
+    /// Gets the browser storage, if it is available.
+    fn get(self) -> Option<web_sys::Storage> {
+        let window = web_sys::window()?;
+        let storage = match self {
+            DraftStorage::Local => window.local_storage(),
+            DraftStorage::Session => window.session_storage(),
+        };
+        storage.ok().flatten()
+    }
+
+    /// Deletes the draft saved under `key` in this storage.
+    ///
+    /// This is the same as [`Form::discard_draft`](crate::Form::discard_draft),
+    /// for when the [`Form`](crate::Form) can't be reached, like in the
+    /// `on_submit` callback of
+    /// [`get_ajax_form`](FormToolData::get_ajax_form). It does nothing on the
+    /// server.
+    pub fn discard(self, key: &str) {
+        if let Some(storage) = self.get() {
+            let _ = storage.remove_item(key);
+        }
+    }
+}
+
+/// A function that serializes the form data for saving.
+pub(crate) trait SerializeFn<FD>: Fn(&FD) -> Option<String> + 'static {}
+impl<FD, F> SerializeFn<FD> for F where F: Fn(&FD) -> Option<String> + 'static {}
+/// A function that deserializes a saved draft.
+pub(crate) trait DeserializeFn<FD>: Fn(&str) -> Option<FD> + 'static {}
+impl<FD, F> DeserializeFn<FD> for F where F: Fn(&str) -> Option<FD> + 'static {}
+/// A function that resets a field that is left out of drafts to its value in
+/// the initial form data.
+pub(crate) trait ResetFn<FD>: Fn(&mut FD, &FD) + 'static {}
+impl<FD, F> ResetFn<FD> for F where F: Fn(&mut FD, &FD) + 'static {}
+
+/// The settings for saving the form data as a draft in the browser's
+/// storage, from [`FormBuilder::autosave`].
+pub(crate) struct DraftSettings<FD> {
+    /// The storage to save the draft to.
+    storage: DraftStorage,
+    /// The key the draft is saved under.
+    key: String,
+    /// How long the form data must stop changing for before it is saved.
+    delay: Duration,
+    serialize: Box<dyn SerializeFn<FD>>,
+    deserialize: Box<dyn DeserializeFn<FD>>,
+}
+
+/// The draft of a built form.
+pub(crate) struct Draft<FD: 'static> {
+    settings: Rc<DraftSettings<FD>>,
+    /// The form data that is saved.
+    fd: RwSignal<FD>,
+    /// The form data the form was built with, which the fields left out of
+    /// drafts are reset to.
+    initial: RwSignal<FD>,
+    /// The functions that reset the fields left out of drafts.
+    resets: Rc<[Rc<dyn ResetFn<FD>>]>,
+    /// The timeout for the next save, if there is one.
+    pending: Rc<Cell<Option<TimeoutHandle>>>,
+}
+
+impl<FD> Clone for Draft<FD> {
+    fn clone(&self) -> Self {
+        Draft {
+            settings: self.settings.clone(),
+            fd: self.fd,
+            initial: self.initial,
+            resets: self.resets.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+}
+
+impl<FD: FormToolData> Draft<FD> {
+    /// Restores the saved draft into the form data signal, and saves the form
+    /// data whenever it changes.
+    ///
+    /// The fields reset by `resets` are never saved, and keep their value
+    /// from `initial` when the draft is restored.
+    ///
+    /// This is done in an effect, so the storage is only used in the browser,
+    /// after hydrating.
+    pub(crate) fn install(
+        settings: DraftSettings<FD>,
+        fd: RwSignal<FD>,
+        initial: RwSignal<FD>,
+        resets: Vec<Rc<dyn ResetFn<FD>>>,
+    ) -> Self {
+        let draft = Draft {
+            settings: Rc::new(settings),
+            fd,
+            initial,
+            resets: Rc::from(resets),
+            pending: Rc::new(Cell::new(None)),
+        };
+
+        let install_draft = draft.clone();
+        create_effect(move |prev: Option<()>| {
+            let draft = &install_draft;
+            if prev.is_none() {
+                if let Some(restored) = draft.restore() {
+                    fd.set(restored);
+                }
+                fd.track();
+                return;
+            }
+
+            fd.track();
+            draft.cancel_pending();
+            let save_draft = draft.clone();
+            let save = move || {
+                save_draft.pending.set(None);
+                save_draft.save();
+            };
+            draft
+                .pending
+                .set(set_timeout_with_handle(save, draft.settings.delay).ok());
+        });
+
+        draft
+    }
+
+    /// Resets the fields that are left out of drafts.
+    fn reset_fields(&self, fd: &mut FD) {
+        self.initial.with_untracked(|initial| {
+            for reset in self.resets.iter() {
+                reset(fd, initial);
+            }
+        });
+    }
+
+    /// Loads the saved draft, if there is one.
+    fn restore(&self) -> Option<FD> {
+        let storage = self.settings.storage.get()?;
+        let value = storage.get_item(&self.settings.key).ok()??;
+        let mut restored = (self.settings.deserialize)(&value)?;
+        // drafts saved before a field was left out may still hold it
+        self.reset_fields(&mut restored);
+        Some(restored)
+    }
+
+    /// Saves the form data to the storage.
+    fn save(&self) {
+        let value = self.fd.with_untracked(|fd| match self.resets.is_empty() {
+            true => (self.settings.serialize)(fd),
+            false => {
+                let mut fd = fd.clone();
+                self.reset_fields(&mut fd);
+                (self.settings.serialize)(&fd)
+            }
+        });
+        let (Some(storage), Some(value)) = (self.settings.storage.get(), value) else {
+            return;
+        };
+        // failing to save, like when the storage is full, just means there
+        // will be no draft to restore
+        let _ = storage.set_item(&self.settings.key, &value);
+    }
+
+    /// Cancels the pending save, if there is one.
+    fn cancel_pending(&self) {
+        if let Some(handle) = self.pending.take() {
+            handle.clear();
+        }
+    }
+
+    /// Saves the pending changes now, if there are any.
+    ///
+    /// This is done when the form is submitted, so a save can't happen after
+    /// the draft is discarded for the submission.
+    pub(crate) fn flush(&self) {
+        if let Some(handle) = self.pending.take() {
+            handle.clear();
+            self.save();
+        }
+    }
+
+    /// Deletes the saved draft, and cancels the pending save.
+    pub(crate) fn discard(&self) {
+        self.cancel_pending();
+        self.settings.storage.discard(&self.settings.key);
+    }
+}
+
+impl<FD: FormToolData + Serialize + DeserializeOwned> FormBuilder<FD> {
+    /// Saves the form data as a draft in the browser's storage, so it isn't
+    /// lost if the tab crashes or the user navigates away.
+    ///
+    /// The form data is saved under `key` once it has stopped changing for
+    /// `delay`, and the draft is restored when the form is built. Password
+    /// controls, including text inputs made with
+    /// [`password`](crate::controls::ControlBuilder::password), are left out
+    /// of the draft, along with any control built with
+    /// [`in_draft(false)`](crate::controls::ControlBuilder::in_draft). Those
+    /// fields keep the value they were built with.
+    ///
+    /// When the form is built with [`get_form`](FormToolData::get_form) or
+    /// [`get_action_form`](FormToolData::get_action_form), the draft is
+    /// discarded once the action succeeds. Otherwise, the draft is kept until
+    /// it is discarded with
+    /// [`Form::discard_draft`](crate::Form::discard_draft) or
+    /// [`DraftStorage::discard`], which should be done once the form is
+    /// submitted successfully.
+    ///
+    /// Forms built with [`get_query_form`](FormToolData::get_query_form)
+    /// keep their data in the query string instead, and
+    /// [`get_review`](FormToolData::get_review) can't be edited, so they
+    /// don't save drafts.
+    pub fn autosave(mut self, storage: DraftStorage, key: impl ToString, delay: Duration) -> Self {
+        self.draft = Some(DraftSettings {
+            storage,
+            key: key.to_string(),
+            delay,
+            serialize: Box::new(|fd| serde_json::to_string(fd).ok()),
+            deserialize: Box::new(|value| serde_json::from_str(value).ok()),
+        });
+        self
+    }
+}
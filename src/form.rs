@@ -1,19 +1,31 @@
-use crate::{controls::ValidationFn, form_builder::FormBuilder, styles::FormStyle};
+use crate::{
+    changes::{self, ChangeFn, FieldChange},
+    controls::ValidationFn,
+    derive::{Derivation, DeriveFn},
+    draft::Draft,
+    form_builder::FormBuilder,
+    guard::SavedState,
+    history::History,
+    styles::FormStyle,
+};
 use ev::SubmitEvent;
 use leptos::{
     server_fn::{client::Client, codec::PostUrl, request::ClientReq, ServerFn},
     *,
 };
 use serde::de::DeserializeOwned;
-use std::rc::Rc;
+use std::{rc::Rc, time::Duration};
 use web_sys::FormData;
 
 /// A type that can be used to validate the form data.
 ///
 /// This can be useful to use the same validation logic on the front
 /// end and backend without duplicating the logic.
-pub struct FormValidator<FD> {
+pub struct FormValidator<FD: 'static> {
     pub(crate) validations: Vec<Rc<dyn ValidationFn<FD>>>,
+    pub(crate) warnings: Vec<Rc<dyn ValidationFn<FD>>>,
+    pub(crate) derivations: Vec<Rc<dyn DeriveFn<FD>>>,
+    pub(crate) change_fns: Vec<Rc<dyn ChangeFn<FD>>>,
 }
 
 impl<FD: FormToolData> FormValidator<FD> {
@@ -21,12 +33,87 @@ impl<FD: FormToolData> FormValidator<FD> {
     ///
     /// This runs all the validation functions for all the fields
     /// in the form. The first falure to occur (if any) will be returned.
+    ///
+    /// The form data is validated with the derived fields recomputed, see
+    /// [`derive`](Self::derive), so the derived values sent by the client
+    /// are never trusted. This is done on a copy of the form data; use
+    /// [`validate_and_derive`](Self::validate_and_derive) to keep the
+    /// derived values.
     pub fn validate(&self, form_data: &FD) -> Result<(), String> {
+        self.with_derived(form_data, |form_data| {
+            for v in self.validations.iter() {
+                (*v)(form_data)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Applies the derived fields to the given form data, then validates it.
+    ///
+    /// This is like [`validate`](Self::validate), but the form data is
+    /// derived in place, so the form data that was validated is the form
+    /// data the caller keeps using.
+    pub fn validate_and_derive(&self, form_data: &mut FD) -> Result<(), String> {
+        self.derive(form_data);
         for v in self.validations.iter() {
             (*v)(form_data)?;
         }
         Ok(())
     }
+
+    /// Applies the derived fields to the given form data.
+    ///
+    /// This recomputes the fields defined with
+    /// [`FormBuilder::derive`] and [`FormBuilder::derive_until_edited`],
+    /// so the server doesn't need to trust the values sent by the client.
+    pub fn derive(&self, form_data: &mut FD) {
+        for d in self.derivations.iter() {
+            (*d)(form_data);
+        }
+    }
+
+    /// Gets the controls that differ between the `initial` and `current`
+    /// form data.
+    ///
+    /// Each control with a name is compared using its field's
+    /// [`PartialEq`] implementation. The changes are listed in the order the
+    /// controls were added, with the old and new values formatted the same
+    /// way as in [`get_review`](FormToolData::get_review). This is useful
+    /// for keeping an audit trail of what a user edited.
+    pub fn changes(&self, initial: &FD, current: &FD) -> Vec<FieldChange> {
+        let initial = create_rw_signal(initial.clone());
+        let current = create_rw_signal(current.clone());
+        let changes = changes::changes(&self.change_fns, initial, current);
+        initial.dispose();
+        current.dispose();
+        changes
+    }
+
+    /// Helper for running `f` on the form data with the derived fields
+    /// applied, only cloning it if there are any.
+    fn with_derived<R>(&self, form_data: &FD, f: impl FnOnce(&FD) -> R) -> R {
+        if self.derivations.is_empty() {
+            return f(form_data);
+        }
+        let mut derived = form_data.clone();
+        self.derive(&mut derived);
+        f(&derived)
+    }
+
+    /// Gets the warnings for the given form data.
+    ///
+    /// This runs all the warning functions for all the fields in the form,
+    /// returning the message of each one that fails. Warnings don't make
+    /// the data invalid, so they are not reported by
+    /// [`validate`](Self::validate).
+    pub fn warnings(&self, form_data: &FD) -> Vec<String> {
+        self.with_derived(form_data, |form_data| {
+            self.warnings
+                .iter()
+                .filter_map(|w| (*w)(form_data).err())
+                .collect()
+        })
+    }
 }
 
 /// A constructed, rendered form object.
@@ -39,6 +126,20 @@ pub struct Form<FD: FormToolData> {
     pub fd: RwSignal<FD>,
     /// The list of validations
     pub(crate) validations: Vec<Rc<dyn ValidationFn<FD>>>,
+    /// The list of warnings
+    pub(crate) warnings: Vec<Rc<dyn ValidationFn<FD>>>,
+    /// The list of derived fields
+    pub(crate) derivations: Vec<Derivation<FD>>,
+    /// The list of functions that find the controls that changed
+    pub(crate) change_fns: Vec<Rc<dyn ChangeFn<FD>>>,
+    /// The form data the form was built with
+    pub(crate) initial: RwSignal<FD>,
+    /// The settings for saving the form data as a draft
+    pub(crate) draft: Option<Draft<FD>>,
+    /// The form data that was last saved, if unsaved changes are guarded
+    pub(crate) saved: Option<SavedState<FD>>,
+    /// The undo and redo history, if it is kept
+    pub(crate) history: Option<History<FD>>,
     pub(crate) view: View,
 }
 
@@ -47,6 +148,9 @@ impl<FD: FormToolData> Form<FD> {
     pub fn validator(&self) -> FormValidator<FD> {
         FormValidator {
             validations: self.validations.clone(),
+            warnings: self.warnings.clone(),
+            derivations: self.derivations.iter().map(|d| d.apply.clone()).collect(),
+            change_fns: self.change_fns.clone(),
         }
     }
 
@@ -56,6 +160,110 @@ impl<FD: FormToolData> Form<FD> {
         validator.validate(&self.fd.get_untracked())
     }
 
+    /// Gets the warnings for the [`FormToolData`].
+    pub fn warnings(&self) -> Vec<String> {
+        let validator = self.validator();
+        validator.warnings(&self.fd.get_untracked())
+    }
+
+    /// Gets the controls that have changed since the form was built.
+    ///
+    /// See [`FormValidator::changes`].
+    pub fn changes(&self) -> Vec<FieldChange> {
+        changes::changes(&self.change_fns, self.initial, self.fd)
+    }
+
+    /// Gets a signal of the controls that have changed since the form was
+    /// built.
+    pub fn changes_signal(&self) -> Signal<Vec<FieldChange>> {
+        let (change_fns, initial, fd) = (self.change_fns.clone(), self.initial, self.fd);
+        Signal::derive(move || {
+            fd.track();
+            changes::changes(&change_fns, initial, fd)
+        })
+    }
+
+    /// Deletes the draft saved by
+    /// [`FormBuilder::autosave`](crate::FormBuilder::autosave).
+    ///
+    /// This should be called once the form is submitted successfully, unless
+    /// the form was built with [`get_form`](FormToolData::get_form) or
+    /// [`get_action_form`](FormToolData::get_action_form), which do this
+    /// when the action succeeds. This does nothing if the form doesn't save
+    /// drafts.
+    pub fn discard_draft(&self) {
+        if let Some(ref draft) = self.draft {
+            draft.discard();
+        }
+    }
+
+    /// Marks the changes to the form data as saved.
+    ///
+    /// This stops
+    /// [`FormBuilder::guard_unsaved_changes`](crate::FormBuilder::guard_unsaved_changes)
+    /// from asking the user before leaving the page, until the form data
+    /// changes again. This does nothing if the form doesn't guard unsaved
+    /// changes.
+    pub fn mark_saved(&self) {
+        if let Some(ref saved) = self.saved {
+            saved.mark_saved(self.fd.get_untracked());
+        }
+    }
+
+    /// Asks the user if they want to leave the page, if the form has unsaved
+    /// changes.
+    ///
+    /// Returns `true` if the user may leave, because there are no unsaved
+    /// changes or the user agreed to leave. This always returns `true` if
+    /// the form doesn't guard unsaved changes.
+    ///
+    /// [`FormBuilder::guard_unsaved_changes`](crate::FormBuilder::guard_unsaved_changes)
+    /// can't see navigating in code, like with
+    /// [`use_navigate`](leptos_router::use_navigate), so this should be
+    /// checked first.
+    pub fn confirm_leave(&self) -> bool {
+        match self.saved {
+            Some(ref saved) => saved.confirm_leave(),
+            None => true,
+        }
+    }
+
+    /// Undoes the last change to the form data.
+    ///
+    /// This does nothing if there is nothing to undo, or if the form doesn't
+    /// keep a [`history`](crate::FormBuilder::history).
+    pub fn undo(&self) {
+        if let Some(history) = self.history {
+            history.undo();
+        }
+    }
+
+    /// Redoes the last change to the form data that was undone.
+    ///
+    /// This does nothing if there is nothing to redo, or if the form doesn't
+    /// keep a [`history`](crate::FormBuilder::history).
+    pub fn redo(&self) {
+        if let Some(history) = self.history {
+            history.redo();
+        }
+    }
+
+    /// Gets a signal of whether there is a change to undo.
+    pub fn can_undo(&self) -> Signal<bool> {
+        match self.history {
+            Some(history) => history.can_undo(),
+            None => Signal::derive(|| false),
+        }
+    }
+
+    /// Gets a signal of whether there is a change to redo.
+    pub fn can_redo(&self) -> Signal<bool> {
+        match self.history {
+            Some(history) => history.can_redo(),
+            None => Signal::derive(|| false),
+        }
+    }
+
     /// Gets the view associated with this [`Form`].
     pub fn view(&self) -> View {
         self.view.clone()
@@ -67,6 +275,9 @@ impl<FD: FormToolData> Form<FD> {
             self.fd,
             FormValidator {
                 validations: self.validations,
+                warnings: self.warnings,
+                derivations: self.derivations.into_iter().map(|d| d.apply).collect(),
+                change_fns: self.change_fns,
             },
             self.view,
         )
@@ -226,6 +437,63 @@ pub trait FormToolData: Clone + 'static {
         builder.build_form_controls(self, style)
     }
 
+    /// Constructs a [`Form`] for this [`FormToolData`] type that keeps the
+    /// form data in the query string.
+    ///
+    /// Like [`get_form_controls`](Self::get_form_controls), this renders
+    /// the form without wrapping it in any form html elements. This is
+    /// useful for search and filter forms, as the state of the form can be
+    /// shared and bookmarked, and works with the back and forward buttons.
+    ///
+    /// Each control with a name is synced to the query parameter with that
+    /// name, using the control's parse functions. Controls that have the
+    /// value they have in `self` are left out of the query string. If
+    /// `debounce` is set, the query string is only updated once the form
+    /// data has stopped changing for that long. Only the first of the
+    /// updates that come less than a second apart adds a history entry; the
+    /// rest replace it, so typing doesn't add an entry for every key. This
+    /// window can be changed with
+    /// [`FormBuilder::query_burst`](crate::FormBuilder::query_burst).
+    ///
+    /// This must be used inside of a leptos_router
+    /// [`Router`](leptos_router::Router).
+    ///
+    /// For the other ways to construct a [`Form`], see:
+    /// - [`get_form`](Self::get_form)
+    /// - [`get_action_form`](Self::get_action_form)
+    /// - [`get_plain_form`](Self::get_plain_form)
+    /// - [`get_form_controls`](Self::get_form_controls)
+    fn get_query_form(
+        self,
+        debounce: Option<Duration>,
+        style: Self::Style,
+        context: Self::Context,
+    ) -> Form<Self> {
+        let builder = FormBuilder::new(context);
+        let builder = Self::build_form(builder);
+        builder.build_query_form(self, style, debounce)
+    }
+
+    /// Constructs a [`Form`] for this [`FormToolData`] type that shows the
+    /// form data read-only.
+    ///
+    /// Instead of the controls, each control is rendered as its label and
+    /// its current value, using [`FormStyle::review_item`]. Headings, outputs
+    /// and groups are kept, and controls hidden with `show_when` stay hidden.
+    /// This is useful for a confirmation step before submitting, or for
+    /// showing a submitted record.
+    ///
+    /// For the other ways to construct a [`Form`], see:
+    /// - [`get_form`](Self::get_form)
+    /// - [`get_action_form`](Self::get_action_form)
+    /// - [`get_plain_form`](Self::get_plain_form)
+    /// - [`get_form_controls`](Self::get_form_controls)
+    fn get_review(self, style: Self::Style, context: Self::Context) -> Form<Self> {
+        let builder = FormBuilder::new(context);
+        let builder = Self::build_form(builder);
+        builder.build_review(self, style)
+    }
+
     /// Gets a [`FormValidator`] for this [`FormToolData`].
     ///
     /// This doesn't render the view, but just collects all the validation
@@ -235,7 +503,7 @@ pub trait FormToolData: Clone + 'static {
     /// However, the code to render the views are not configured out, it
     /// simply doesn't run, so the view needs to compile even on the server.
     fn get_validator(context: Self::Context) -> FormValidator<Self> {
-        let builder = FormBuilder::new(context);
+        let builder = FormBuilder::new_unrendered(context);
         let builder = Self::build_form(builder);
         builder.validator()
     }
@@ -244,9 +512,20 @@ pub trait FormToolData: Clone + 'static {
     ///
     /// This is shorthand for creating a validator with
     /// [`get_validator`](Self::get_validator)()
-    /// and then calling `validator.validate(&self, context)`.
+    /// and then calling `validator.validate(self)`.
     fn validate(&self, context: Self::Context) -> Result<(), String> {
         let validator = Self::get_validator(context);
         validator.validate(self)
     }
+
+    /// Applies the derived fields to this [`FormToolData`] struct, then
+    /// validates it.
+    ///
+    /// This is shorthand for creating a validator with
+    /// [`get_validator`](Self::get_validator)()
+    /// and then calling `validator.validate_and_derive(self)`.
+    fn validate_and_derive(&mut self, context: Self::Context) -> Result<(), String> {
+        let validator = Self::get_validator(context);
+        validator.validate_and_derive(self)
+    }
 }
@@ -1,52 +1,177 @@
 use crate::{
+    changes::{self, ChangeFn},
     controls::{
         BuilderCxFn, BuilderFn, BuiltControlData, BuiltVanityControlData, ControlBuilder,
-        ControlData, ControlRenderData, FieldSetter, ParseFn, RenderFn, ValidationCb, ValidationFn,
-        ValidationState, VanityControlBuilder, VanityControlData,
+        ControlData, ControlRenderData, FieldSetter, ParseFn, RenderFn, ReviewFn, ShowWhenFn,
+        ValidationCb, ValidationFn, ValidationState, ValidationTrigger, VanityControlBuilder,
+        VanityControlData,
     },
+    derive::Derivation,
+    draft::{Draft, DraftSettings, ResetFn},
     form::{Form, FormToolData, FormValidator},
+    guard::{SavedState, UnsavedGuard},
+    history::{History, HistorySettings},
+    messages::{DefaultMessages, MessageCatalog},
+    query::QueryField,
     styles::FormStyle,
 };
 use leptos::{
+    leptos_dom::{helpers::TimeoutHandle, HydrationCtx},
     server_fn::{client::Client, codec::PostUrl, request::ClientReq, ServerFn},
     *,
 };
-use leptos_router::{ActionForm, Form};
+use leptos_router::{use_location, use_navigate, ActionForm, Form, NavigateOptions, ParamsMap};
 use serde::de::DeserializeOwned;
-use std::rc::Rc;
+use std::{cell::Cell, rc::Rc, time::Duration};
+use wasm_bindgen::JsCast;
 use web_sys::{FormData, SubmitEvent};
 
+/// The default for [`FormBuilder::query_burst`].
+const QUERY_BURST: Duration = Duration::from_secs(1);
+
+/// Generates the unique html ids for the controls of a form.
+///
+/// Each form gets its own prefix, so rendering the same form more than once
+/// on a page doesn't produce duplicate ids.
+#[derive(Clone)]
+pub(crate) struct IdGenerator {
+    /// The prefix shared by all the ids of the form.
+    prefix: Rc<str>,
+    /// The number of the next control.
+    next: Rc<Cell<usize>>,
+}
+
+impl IdGenerator {
+    /// Creates a new [`IdGenerator`] with a new, unique prefix.
+    ///
+    /// The prefix comes from the hydration key of the current render, which
+    /// is reset for each server render and is reached in the same order when
+    /// hydrating. This makes the ids the same on the server and the client.
+    fn new() -> Self {
+        let key = HydrationCtx::id().unwrap_or_else(HydrationCtx::peek_always);
+        Self::with_prefix(format!("lft{}", key))
+    }
+
+    /// Creates a new [`IdGenerator`] for a form that is never rendered,
+    /// like for a [`FormValidator`], without using the hydration key.
+    fn unrendered() -> Self {
+        Self::with_prefix(String::from("lft"))
+    }
+
+    fn with_prefix(prefix: String) -> Self {
+        IdGenerator {
+            prefix: Rc::from(prefix),
+            next: Rc::new(Cell::new(0)),
+        }
+    }
+
+    /// Gets the id for the form itself.
+    pub(crate) fn form_id(&self) -> String {
+        self.prefix.to_string()
+    }
+
+    /// Gets a new id for a control of the form.
+    pub(crate) fn next_id(&self) -> String {
+        let number = self.next.get();
+        self.next.set(number + 1);
+        format!("{}-{}", self.prefix, number)
+    }
+}
+
 /// A builder for laying out forms.
 ///
 /// This builder allows you to specify what components should make up the form.
 pub struct FormBuilder<FD: FormToolData> {
     pub(crate) cx: Rc<FD::Context>,
+    /// The generator for the ids of the controls.
+    pub(crate) ids: IdGenerator,
+    /// The catalog used to render parse errors.
+    pub(crate) messages: Rc<dyn MessageCatalog>,
+    /// When the controls validate, unless overridden by the control.
+    pub(crate) validation_trigger: ValidationTrigger,
     /// The list of [`ValidationFn`]s.
     pub(crate) validations: Vec<Rc<dyn ValidationFn<FD>>>,
+    /// The list of [`ValidationFn`]s that only produce warnings.
+    pub(crate) warnings: Vec<Rc<dyn ValidationFn<FD>>>,
+    /// The list of rules that keep fields derived from other fields.
+    pub(crate) derivations: Vec<Derivation<FD>>,
+    /// The settings for saving the form data as a draft.
+    pub(crate) draft: Option<DraftSettings<FD>>,
+    /// The list of functions that reset the fields left out of drafts.
+    pub(crate) draft_resets: Vec<Rc<dyn ResetFn<FD>>>,
+    /// The settings for blocking leaving the page with unsaved changes.
+    pub(crate) unsaved_guard: Option<UnsavedGuard<FD>>,
+    /// The settings for keeping the history of the form data.
+    pub(crate) history: Option<HistorySettings>,
+    /// The list of controls that can be synced to the query string.
+    pub(crate) query_fields: Vec<QueryField<FD>>,
+    /// How long the form data of a query form must stop changing for before
+    /// the next change adds a history entry.
+    pub(crate) query_burst: Duration,
+    /// The list of functions that find the controls that changed.
+    pub(crate) change_fns: Vec<Rc<dyn ChangeFn<FD>>>,
     /// The list of functions that will render the form.
     pub(crate) render_fns: Vec<Box<dyn RenderFn<FD::Style, FD>>>,
+    /// The list of functions that will render the review of the form.
+    pub(crate) review_fns: Vec<Box<dyn ReviewFn<FD::Style, FD>>>,
     /// The list of styling attributes applied on the form level.
     pub(crate) styles: Vec<<FD::Style as FormStyle>::StylingAttributes>,
 }
 
 impl<FD: FormToolData> FormBuilder<FD> {
-    /// Creates a new [`FormBuilder`]
+    /// Creates a new [`FormBuilder`] for rendering a form.
     pub(crate) fn new(cx: FD::Context) -> Self {
+        Self::with_ids(cx, IdGenerator::new())
+    }
+
+    /// Creates a new [`FormBuilder`] for a form that is never rendered, like
+    /// for a [`FormValidator`].
+    pub(crate) fn new_unrendered(cx: FD::Context) -> Self {
+        Self::with_ids(cx, IdGenerator::unrendered())
+    }
+
+    fn with_ids(cx: FD::Context, ids: IdGenerator) -> Self {
         FormBuilder {
             cx: Rc::new(cx),
+            ids,
+            messages: Rc::new(DefaultMessages),
+            validation_trigger: ValidationTrigger::default(),
             validations: Vec::new(),
+            warnings: Vec::new(),
+            derivations: Vec::new(),
+            draft: None,
+            draft_resets: Vec::new(),
+            unsaved_guard: None,
+            history: None,
+            query_fields: Vec::new(),
+            query_burst: QUERY_BURST,
+            change_fns: Vec::new(),
             render_fns: Vec::new(),
+            review_fns: Vec::new(),
             styles: Vec::new(),
         }
     }
 
-    /// Creates a new [`FormBuilder`] with the given Rc'ed context, for
-    //// building a form group.
-    pub(crate) fn new_group(cx: Rc<FD::Context>) -> Self {
+    /// Creates a new [`FormBuilder`] for building a form group, sharing the
+    /// context, id generator and settings of the `parent` builder.
+    pub(crate) fn new_group(parent: &FormBuilder<FD>) -> Self {
         FormBuilder {
-            cx,
+            cx: parent.cx.clone(),
+            ids: parent.ids.clone(),
+            messages: parent.messages.clone(),
+            validation_trigger: parent.validation_trigger,
             validations: Vec::new(),
+            warnings: Vec::new(),
+            derivations: Vec::new(),
+            draft: None,
+            draft_resets: Vec::new(),
+            unsaved_guard: None,
+            history: None,
+            query_fields: Vec::new(),
+            query_burst: QUERY_BURST,
+            change_fns: Vec::new(),
             render_fns: Vec::new(),
+            review_fns: Vec::new(),
             styles: Vec::new(),
         }
     }
@@ -57,6 +182,52 @@ impl<FD: FormToolData> FormBuilder<FD> {
         self
     }
 
+    /// Sets the [`MessageCatalog`] used to render the parse errors of the
+    /// controls added after this.
+    ///
+    /// The errors of the built in parse functions, like `parse_string` and
+    /// `parse_number`, are rendered by the catalog with the control's label
+    /// (or name) as the field name. Numbers that fail to parse are
+    /// [`NotANumber`](crate::ValidationMessage::NotANumber), and other
+    /// values are [`ParseFailed`](crate::ValidationMessage::ParseFailed).
+    /// Custom parse messages are shown as is. This defaults to the english
+    /// [`DefaultMessages`].
+    pub fn messages(mut self, catalog: impl MessageCatalog) -> Self {
+        self.messages = Rc::new(catalog);
+        self
+    }
+
+    /// Sets the [`MessageCatalog`] used to render the parse errors, getting
+    /// it from the form's context.
+    ///
+    /// See [`messages`](Self::messages).
+    pub fn messages_cx<M: MessageCatalog>(self, catalog: impl FnOnce(&FD::Context) -> M) -> Self {
+        let catalog = catalog(&self.cx);
+        self.messages(catalog)
+    }
+
+    /// Sets when the controls added after this validate their values.
+    ///
+    /// This defaults to [`ValidationTrigger::OnInput`], and can be overridden
+    /// for each control with
+    /// [`ControlBuilder::validate_on`](crate::controls::ControlBuilder::validate_on).
+    /// This is independent of when the control updates the form data.
+    pub fn validate_on(mut self, trigger: ValidationTrigger) -> Self {
+        self.validation_trigger = trigger;
+        self
+    }
+
+    /// Sets how long the form data of a query form must stop changing for
+    /// before the next change adds a history entry, instead of replacing the
+    /// last one.
+    ///
+    /// This defaults to one second. It is only used by forms built with
+    /// [`get_query_form`](FormToolData::get_query_form).
+    pub fn query_burst(mut self, burst: Duration) -> Self {
+        self.query_burst = burst;
+        self
+    }
+
     /// Adds a new vanity control to the form.
     pub(crate) fn new_vanity<C: VanityControlData<FD> + Default>(
         mut self,
@@ -107,29 +278,39 @@ impl<FD: FormToolData> FormBuilder<FD> {
         vanity_control: VanityControlBuilder<FD, C>,
     ) {
         let BuiltVanityControlData {
-            render_data,
+            mut render_data,
             getter,
             show_when,
         } = vanity_control.build();
+        render_data.id = self.ids.next_id();
+        let render_data = Rc::new(render_data);
+        let show_when: Option<Rc<dyn ShowWhenFn<FD, FD::Context>>> = show_when.map(Rc::from);
 
         let cx = self.cx.clone();
-        let render_fn = move |fs: Rc<FD::Style>, fd: RwSignal<FD>| {
-            let render_data = Rc::new(render_data);
-            let value_getter =
-                getter.map(|getter| (move || fd.with(|fd| getter(fd))).into_signal());
+        let render_vanity = move |fs: Rc<FD::Style>, fd: RwSignal<FD>| {
+            // memoized so the control only updates when its own value changes
+            let value_getter = getter
+                .clone()
+                .map(|getter| create_memo(move |_| fd.with(|fd| getter(fd))).into());
+            let render_data = render_data.clone();
             let view = move || {
                 VanityControlData::render_control(&*fs, fd, render_data.clone(), value_getter)
             };
-            let view = match show_when {
+            match show_when.clone() {
                 Some(when) => {
+                    let cx = cx.clone();
                     let when = move || when(fd.into(), cx.clone());
                     view! { <Show when=when>{view.clone()}</Show> }
                 }
                 None => view(),
-            };
-            (view, None)
+            }
         };
 
+        if C::shown_in_review() {
+            let render_vanity = render_vanity.clone();
+            self.review_fns.push(Box::new(render_vanity));
+        }
+        let render_fn = move |fs: Rc<FD::Style>, fd: RwSignal<FD>| (render_vanity(fs, fd), None);
         self.render_fns.push(Box::new(render_fn));
     }
 
@@ -138,7 +319,8 @@ impl<FD: FormToolData> FormBuilder<FD> {
         &mut self,
         control: ControlBuilder<FD, C, FDT>,
     ) {
-        let built_control_data = match control.build() {
+        let id = self.ids.next_id();
+        let built_control_data = match control.build(id, &self.messages) {
             Ok(c) => c,
             Err(e) => {
                 let item_name = std::any::type_name::<C>()
@@ -148,42 +330,139 @@ impl<FD: FormToolData> FormBuilder<FD> {
                 panic!("Invalid Component ({}): {}", item_name, e)
             }
         };
+        let query_field = QueryField::new(
+            built_control_data.render_data.clone(),
+            built_control_data.getter.clone(),
+            built_control_data.setter.clone(),
+            built_control_data.parse_fn.clone(),
+            built_control_data.unparse_fn.clone(),
+        );
+        self.query_fields.extend(query_field);
+        let change_fn = changes::change_fn(
+            built_control_data.render_data.clone(),
+            built_control_data.getter.clone(),
+            built_control_data.unparse_fn.clone(),
+        );
+        self.change_fns.extend(change_fn);
+        if !built_control_data.in_draft {
+            let (getter, setter) = (
+                built_control_data.getter.clone(),
+                built_control_data.setter.clone(),
+            );
+            self.draft_resets
+                .push(Rc::new(move |fd: &mut FD, initial: &FD| {
+                    setter(fd, getter(initial))
+                }));
+        }
 
+        let show_when = built_control_data.show_when.clone();
         if let Some(validation_fn) = built_control_data.validation_fn.clone() {
-            let validation_fn = if let Some(show_when) = built_control_data.show_when.clone() {
-                // we want the validation function to always succeed for hidden components
-                // thus, we need to modify the validation function
-                let cx = self.cx.clone();
-                let new_validation_fn = move |fd: &FD| {
-                    let (fd_signal, _) = create_signal(fd.clone());
-                    if !show_when(fd_signal.into(), cx.clone()) {
-                        return Ok(());
-                    }
-                    validation_fn(fd)
-                };
-                Rc::new(new_validation_fn)
-            } else {
-                validation_fn
-            };
-
+            let validation_fn = self.when_shown(validation_fn, show_when.clone());
             self.validations.push(validation_fn);
         }
+        if let Some(warning_fn) = built_control_data.warning_fn.clone() {
+            let warning_fn = self.when_shown(warning_fn, show_when);
+            self.warnings.push(warning_fn);
+        }
+
+        let review_fn = Self::review_control(&built_control_data, self.cx.clone());
+        self.review_fns.push(Box::new(review_fn));
 
+        let trigger = built_control_data
+            .validation_trigger
+            .unwrap_or(self.validation_trigger);
         let cx = self.cx.clone();
         let render_fn = move |fs: Rc<FD::Style>, fd: RwSignal<FD>| {
-            let (view, cb) = Self::build_control_view(fd, fs, built_control_data, cx);
+            let (view, cb) = Self::build_control_view(fd, fs, built_control_data, trigger, cx);
             (view, Some(cb))
         };
 
         self.render_fns.push(Box::new(render_fn));
     }
 
+    /// Helper for creating the function that renders a control's label and
+    /// value when reviewing the form.
+    fn review_control<C: ControlData<FD>, FDT: 'static>(
+        control_data: &BuiltControlData<FD, C, FDT>,
+        cx: Rc<FD::Context>,
+    ) -> impl ReviewFn<FD::Style, FD> {
+        let render_data = control_data.render_data.clone();
+        let getter = control_data.getter.clone();
+        let unparse_fn = control_data.unparse_fn.clone();
+        let show_when = control_data.show_when.clone();
+
+        move |fs: Rc<FD::Style>, fd: RwSignal<FD>| {
+            let label = render_data
+                .data
+                .label()
+                .or(render_data.data.name())
+                .unwrap_or_default()
+                .to_string();
+            let data = render_data.clone();
+            let value = Signal::derive(move || {
+                let value = unparse_fn(fd.with(|fd| getter(fd)));
+                data.data.display_value(fd, &value).unwrap_or_default()
+            });
+            let view = move || fs.review_item(&render_data.styles, label.clone(), value);
+            match show_when {
+                Some(when) => {
+                    let when = move || when(fd.into(), cx.clone());
+                    view! { <Show when=when>{view.clone()}</Show> }
+                }
+                None => view(),
+            }
+        }
+    }
+
+    /// Helper for making a validation function always succeed when the
+    /// control is hidden.
+    fn when_shown(
+        &self,
+        validation_fn: Rc<dyn ValidationFn<FD>>,
+        show_when: Option<Rc<dyn ShowWhenFn<FD, FD::Context>>>,
+    ) -> Rc<dyn ValidationFn<FD>> {
+        let Some(show_when) = show_when else {
+            return validation_fn;
+        };
+
+        // we want the validation function to always succeed for hidden components
+        // thus, we need to modify the validation function
+        let cx = self.cx.clone();
+        let new_validation_fn = move |fd: &FD| {
+            let (fd_signal, _) = create_signal(fd.clone());
+            if !show_when(fd_signal.into(), cx.clone()) {
+                return Ok(());
+            }
+            validation_fn(fd)
+        };
+        Rc::new(new_validation_fn)
+    }
+
+    /// Helper for checking the form data against a control's validation
+    /// and warning functions.
+    ///
+    /// Warnings are only checked if validation passes.
+    fn check(
+        validation_fn: &Option<Rc<dyn ValidationFn<FD>>>,
+        warning_fn: &Option<Rc<dyn ValidationFn<FD>>>,
+        fd: &FD,
+    ) -> ValidationState {
+        if let Some(Err(e)) = validation_fn.as_ref().map(|v| v(fd)) {
+            return ValidationState::ValidationError(e);
+        }
+        if let Some(Err(w)) = warning_fn.as_ref().map(|w| w(fd)) {
+            return ValidationState::Warning(w);
+        }
+        ValidationState::Passed
+    }
+
     /// Helper for building all the functions and everything needed to render
     /// the view.
-    fn build_control_view<C: ControlData<FD>, FDT: 'static>(
+    fn build_control_view<C: ControlData<FD>, FDT: Clone + PartialEq + 'static>(
         fd: RwSignal<FD>,
         fs: Rc<FD::Style>,
         control_data: BuiltControlData<FD, C, FDT>,
+        trigger: ValidationTrigger,
         cx: Rc<FD::Context>,
     ) -> (View, Box<dyn ValidationCb>) {
         let BuiltControlData {
@@ -193,42 +472,124 @@ impl<FD: FormToolData> FormBuilder<FD> {
             parse_fn,
             unparse_fn,
             validation_fn,
+            warning_fn,
             show_when,
+            debounce,
+            ..
         } = control_data;
 
-        let render_data = Rc::new(render_data);
         let (validation_signal, validation_signal_set) = create_signal(ValidationState::Passed);
-        let validation_fn_clone = validation_fn.clone();
-        let initial_value = unparse_fn(fd.with_untracked(|fd| getter(fd)));
+        // the state from the last input, which may not be shown yet
+        // depending on the validation trigger
+        let latest_state = store_value(ValidationState::Passed);
+        // whether the control has lost focus at least once
+        let touched = store_value(false);
+        // a slice of the form data holding just this control's field, so
+        // changes to other fields don't cause this control to update
+        let field = create_memo(move |_| fd.with(|fd| getter(fd)));
+        // set while this control is setting its field, to tell the changes
+        // from this control apart from other changes, like undo
+        let own_input = store_value(false);
+        let setter = Rc::new(move |fd: &mut FD, value: FDT| {
+            own_input.set_value(true);
+            setter(fd, value);
+        });
+        let initial_value = unparse_fn(field.get_untracked());
         let (value_getter, value_setter) = create_signal(initial_value);
-        create_effect(move |_| {
-            fd.track();
-            if validation_signal.get().is_parse_err() {
-                return;
+        let (effect_validation_fn, effect_warning_fn) = (validation_fn.clone(), warning_fn.clone());
+        // the effect returns the field, so the next run can tell if it changed
+        create_effect(move |prev: Option<FDT>| {
+            let field = field.get();
+            let state = validation_signal.get();
+            let changed_elsewhere =
+                prev.is_some_and(|prev| prev != field) && !own_input.get_value();
+            if changed_elsewhere {
+                // the input that failed to parse is replaced by the new value,
+                // so the parse error doesn't apply anymore
+                if latest_state.with_value(|s| s.is_parse_err()) {
+                    latest_state.set_value(ValidationState::Passed);
+                }
+                if state.is_parse_err() {
+                    let new_state = fd.with_untracked(|fd| {
+                        Self::check(&effect_validation_fn, &effect_warning_fn, fd)
+                    });
+                    validation_signal_set.set(new_state);
+                }
+            } else if state.is_parse_err() || latest_state.with_value(|s| s.is_parse_err()) {
+                return field;
             }
+            value_setter.set(unparse_fn(field.clone()));
+            field
+        });
+        let value_getter = value_getter.into();
 
-            let fd = fd.get_untracked();
+        // rerun validation if it is failing or warning, as it may depend on
+        // other fields. The whole form is only tracked while it is.
+        if validation_fn.is_some() || warning_fn.is_some() {
+            let validation_fn = validation_fn.clone();
+            let warning_fn = warning_fn.clone();
+            create_effect(move |_| {
+                let state = validation_signal.get();
+                if !state.is_validation_err() && !state.is_warning() {
+                    return;
+                }
 
-            // rerun validation if it is failing
-            if validation_signal.get_untracked().is_validation_err() {
-                if let Some(ref validation_fn) = validation_fn_clone {
-                    let validation_result = validation_fn(&fd);
-                    // if validation succeeds this time, resolve the validation error
-                    if validation_result.is_ok() {
-                        validation_signal_set.set(ValidationState::Passed);
-                    }
+                let new_state = fd.with(|fd| Self::check(&validation_fn, &warning_fn, fd));
+                // only resolve the error or warning, new ones show up when the
+                // user changes this field
+                if !new_state.is_err() && new_state != state {
+                    validation_signal_set.set(new_state);
                 }
+            });
+        }
+
+        // decides if the state from an input should be shown now
+        let report_input = move |new_state: ValidationState| {
+            own_input.set_value(false);
+            latest_state.set_value(new_state.clone());
+            let show = match trigger {
+                ValidationTrigger::OnInput => true,
+                ValidationTrigger::AfterFirstBlur => touched.get_value(),
+                ValidationTrigger::OnBlur | ValidationTrigger::OnSubmit => false,
+            };
+            // errors and warnings that have been fixed are always cleared
+            // right away
+            let state = validation_signal.get_untracked();
+            let fixed = (state.is_err() && !new_state.is_err())
+                || (state.is_warning() && new_state.is_passed());
+            if show || fixed {
+                validation_signal_set.set(new_state);
             }
+        };
 
-            let value = unparse_fn(getter(&fd));
-            value_setter.set(value);
-        });
-        let value_getter = value_getter.into();
+        let value_setter = Self::create_value_setter(
+            validation_fn.clone(),
+            warning_fn.clone(),
+            report_input,
+            parse_fn,
+            setter,
+            fd,
+        );
+        // the pending input of a debounced control is flushed on blur and on
+        // submit, so no input is lost
+        let (value_setter, flush) = match debounce {
+            Some(delay) => {
+                let (value_setter, flush) = Self::debounce_setter(value_setter, delay);
+                (value_setter, Some(flush))
+            }
+            None => (value_setter, None),
+        };
 
         let validation_fn_clone = validation_fn.clone();
+        let warning_fn_clone = warning_fn.clone();
         let cloned_show_when = show_when.clone();
         let cloned_cx = cx.clone();
+        let cloned_flush = flush.clone();
         let validation_cb = move || {
+            if let Some(ref flush) = cloned_flush {
+                flush();
+            }
+
             // validation for non-visible fields always succeeds
             if let Some(ref show_when) = cloned_show_when {
                 if !show_when(fd.into(), cloned_cx.clone()) {
@@ -237,38 +598,62 @@ impl<FD: FormToolData> FormBuilder<FD> {
             }
 
             // fail on parse falures
-            if validation_signal
-                .try_get_untracked()
-                .is_some_and(|v| v.is_parse_err())
-            {
+            if let Some(parse_err) = latest_state.try_get_value().filter(|v| v.is_parse_err()) {
+                validation_signal_set.set(parse_err);
                 return false;
             }
 
-            // run the validation function on the value now
-            let validation_fn = match validation_fn_clone {
-                Some(ref v) => v,
-                None => return true, // No validation function so validation passes
-            };
-
-            let data = fd.get_untracked();
-            let validation_result = validation_fn(&data);
-            let succeeded = validation_result.is_ok();
-            let new_state = match validation_result {
-                Ok(()) => ValidationState::Passed,
-                Err(e) => ValidationState::ValidationError(e),
-            };
+            // run the validation function on the value now, warnings don't
+            // stop the submission
+            let new_state =
+                fd.with_untracked(|fd| Self::check(&validation_fn_clone, &warning_fn_clone, fd));
+            let succeeded = !new_state.is_err();
             validation_signal_set.set(new_state);
             succeeded
         };
         let validation_cb = Box::new(validation_cb);
 
-        let value_setter = Self::create_value_setter(
-            validation_fn.clone(),
-            validation_signal_set,
-            parse_fn,
-            setter,
-            fd,
+        let validate_on_blur = matches!(
+            trigger,
+            ValidationTrigger::OnBlur | ValidationTrigger::AfterFirstBlur
         );
+        let listen_for_blur = validate_on_blur || flush.is_some();
+        let on_blur = move || {
+            if let Some(ref flush) = flush {
+                flush();
+            }
+            if !validate_on_blur {
+                return;
+            }
+
+            touched.set_value(true);
+            let state = latest_state.get_value();
+            let new_state = match state.is_parse_err() {
+                true => state,
+                false => fd.with_untracked(|fd| Self::check(&validation_fn, &warning_fn, fd)),
+            };
+            validation_signal_set.set(new_state);
+        };
+
+        // focusout bubbles up to the window, so the control losing focus is
+        // found there by the id it is rendered with, without wrapping the
+        // control's view. Moving focus within the control, like between radio
+        // buttons, is ignored. Effects only run in the browser, so the
+        // listener is never added on the server.
+        if listen_for_blur {
+            let id = fs.control_id(&render_data.styles, &render_data.id);
+            create_effect(move |_| {
+                let (id, on_blur) = (id.clone(), on_blur.clone());
+                let focus_out = window_event_listener(ev::focusout, move |ev| {
+                    if Self::in_control(&id, ev.target())
+                        && !Self::in_control(&id, ev.related_target())
+                    {
+                        on_blur();
+                    }
+                });
+                on_cleanup(move || focus_out.remove());
+            });
+        }
 
         let view = move || {
             C::render_control(
@@ -290,11 +675,57 @@ impl<FD: FormToolData> FormBuilder<FD> {
         (view, validation_cb)
     }
 
+    /// Checks if the event target is part of the control with the given id.
+    ///
+    /// The elements of a control either have the control's id, or one that
+    /// starts with it, like the options of a radio group.
+    fn in_control(id: &str, target: Option<web_sys::EventTarget>) -> bool {
+        let Some(element) = target.and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        else {
+            return false;
+        };
+        let element_id = element.id();
+        element_id
+            .strip_prefix(id)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+    }
+
+    /// Marks the changes as saved and discards the draft when the `action`
+    /// succeeds.
+    ///
+    /// `submitted` should be set to the form data when it is submitted, so
+    /// changes made while waiting for the server are not marked as saved.
+    fn on_success<I: 'static, T: 'static, E: 'static>(
+        saved: Option<SavedState<FD>>,
+        draft: Option<Draft<FD>>,
+        submitted: StoredValue<Option<FD>>,
+        action: Action<I, Result<T, E>>,
+    ) {
+        if saved.is_none() && draft.is_none() {
+            return;
+        }
+        create_effect(move |_| {
+            if !action.value().with(|v| matches!(v, Some(Ok(_)))) {
+                return;
+            }
+            let Some(data) = submitted.try_update_value(Option::take).flatten() else {
+                return;
+            };
+            if let Some(ref draft) = draft {
+                draft.discard();
+            }
+            if let Some(ref saved) = saved {
+                saved.mark_saved(data);
+            }
+        });
+    }
+
     /// Helper for creating a setter function.
     fn create_value_setter<CRT: 'static, FDT: 'static>(
         validation_fn: Option<Rc<dyn ValidationFn<FD>>>,
-        validation_signal_set: WriteSignal<ValidationState>,
-        parse_fn: Box<dyn ParseFn<CRT, FDT>>,
+        warning_fn: Option<Rc<dyn ValidationFn<FD>>>,
+        report_input: impl Fn(ValidationState) + 'static,
+        parse_fn: Rc<dyn ParseFn<CRT, FDT>>,
         setter: Rc<dyn FieldSetter<FD, FDT>>,
         fd: RwSignal<FD>,
     ) -> SignalSetter<CRT> {
@@ -302,7 +733,7 @@ impl<FD: FormToolData> FormBuilder<FD> {
             let parsed = match parse_fn(value) {
                 Ok(p) => p,
                 Err(e) => {
-                    validation_signal_set.set(ValidationState::ParseError(e));
+                    report_input(ValidationState::ParseError(e));
                     return;
                 }
             };
@@ -313,24 +744,41 @@ impl<FD: FormToolData> FormBuilder<FD> {
             });
 
             // run validation
-            let validation_fn = match validation_fn {
-                Some(ref v) => v,
-                None => {
-                    // No validation function so validation passes
-                    validation_signal_set.set(ValidationState::Passed);
-                    return;
+            let new_state = fd.with_untracked(|fd| Self::check(&validation_fn, &warning_fn, fd));
+            report_input(new_state);
+        };
+        value_setter.into_signal_setter()
+    }
+
+    /// Helper for debouncing a setter, so it is only called once the value
+    /// has stopped changing for `delay`.
+    ///
+    /// Returns the debounced setter and a function that immediately sets the
+    /// pending value, if there is one.
+    fn debounce_setter<CRT: 'static>(
+        value_setter: SignalSetter<CRT>,
+        delay: Duration,
+    ) -> (SignalSetter<CRT>, impl Fn() + Clone + 'static) {
+        let pending = store_value(None::<(CRT, Option<TimeoutHandle>)>);
+
+        let flush = move || {
+            if let Some((value, handle)) = pending.try_update_value(Option::take).flatten() {
+                if let Some(handle) = handle {
+                    handle.clear();
                 }
-            };
+                value_setter.set(value);
+            }
+        };
 
-            let data = fd.get_untracked();
-            let validation_result = validation_fn(&data);
-            let new_state = match validation_result {
-                Ok(()) => ValidationState::Passed,
-                Err(e) => ValidationState::ValidationError(e),
-            };
-            validation_signal_set.set(new_state);
+        let debounced_setter = move |value: CRT| {
+            if let Some((_, Some(handle))) = pending.try_update_value(Option::take).flatten() {
+                handle.clear();
+            }
+            let handle = set_timeout_with_handle(flush, delay).ok();
+            pending.set_value(Some((value, handle)));
         };
-        value_setter.into_signal_setter()
+
+        (debounced_setter.into_signal_setter(), flush)
     }
 
     /// Builds the direct send version of the form.
@@ -349,6 +797,15 @@ impl<FD: FormToolData> FormBuilder<FD> {
     {
         let fd = create_rw_signal(fd);
         let fs = Rc::new(fs);
+        for derivation in self.derivations.iter() {
+            (derivation.install)(fd);
+        }
+        let initial = create_rw_signal(fd.get_untracked());
+        let saved = self.unsaved_guard.as_ref().map(|guard| guard.install(fd));
+        let draft = self
+            .draft
+            .map(|settings| Draft::install(settings, fd, initial, self.draft_resets));
+        let history = self.history.map(|settings| History::install(settings, fd));
 
         let (views, validation_cbs): (Vec<_>, Vec<_>) = self
             .render_fns
@@ -358,9 +815,14 @@ impl<FD: FormToolData> FormBuilder<FD> {
 
         let elements = fs.form_frame(ControlRenderData {
             data: views.into_view(),
+            id: self.ids.form_id(),
+            required: false,
             styles: self.styles,
         });
 
+        let submitted = store_value(None);
+        Self::on_success(saved.clone(), draft.clone(), submitted, action);
+
         let on_submit = move |ev: SubmitEvent| {
             if ev.default_prevented() {
                 return;
@@ -372,6 +834,7 @@ impl<FD: FormToolData> FormBuilder<FD> {
                 }
             }
             on_submit(ev, fd);
+            submitted.set_value(Some(fd.get_untracked()));
 
             let server_fn = ServFn::from(fd.get_untracked());
             action.dispatch(server_fn);
@@ -386,6 +849,13 @@ impl<FD: FormToolData> FormBuilder<FD> {
         Form {
             fd,
             validations: self.validations,
+            warnings: self.warnings,
+            derivations: self.derivations,
+            change_fns: self.change_fns,
+            initial,
+            draft,
+            saved,
+            history,
             view,
         }
     }
@@ -399,6 +869,15 @@ impl<FD: FormToolData> FormBuilder<FD> {
     ) -> Form<FD> {
         let fd = create_rw_signal(fd);
         let fs = Rc::new(fs);
+        for derivation in self.derivations.iter() {
+            (derivation.install)(fd);
+        }
+        let initial = create_rw_signal(fd.get_untracked());
+        let saved = self.unsaved_guard.as_ref().map(|guard| guard.install(fd));
+        let draft = self
+            .draft
+            .map(|settings| Draft::install(settings, fd, initial, self.draft_resets));
+        let history = self.history.map(|settings| History::install(settings, fd));
 
         let (views, validation_cbs): (Vec<_>, Vec<_>) = self
             .render_fns
@@ -408,9 +887,12 @@ impl<FD: FormToolData> FormBuilder<FD> {
 
         let elements = fs.form_frame(ControlRenderData {
             data: views.into_view(),
+            id: self.ids.form_id(),
+            required: false,
             styles: self.styles,
         });
 
+        let submit_draft = draft.clone();
         let on_submit = move |ev: SubmitEvent| {
             if ev.default_prevented() {
                 return;
@@ -422,6 +904,9 @@ impl<FD: FormToolData> FormBuilder<FD> {
                 }
             }
             ev.prevent_default();
+            if let Some(ref draft) = submit_draft {
+                draft.flush();
+            }
             on_submit(ev, fd);
         };
 
@@ -435,6 +920,13 @@ impl<FD: FormToolData> FormBuilder<FD> {
         Form {
             fd,
             validations: self.validations,
+            warnings: self.warnings,
+            derivations: self.derivations,
+            change_fns: self.change_fns,
+            initial,
+            draft,
+            saved,
+            history,
             view,
         }
     }
@@ -454,6 +946,15 @@ impl<FD: FormToolData> FormBuilder<FD> {
     {
         let fd = create_rw_signal(fd);
         let fs = Rc::new(fs);
+        for derivation in self.derivations.iter() {
+            (derivation.install)(fd);
+        }
+        let initial = create_rw_signal(fd.get_untracked());
+        let saved = self.unsaved_guard.as_ref().map(|guard| guard.install(fd));
+        let draft = self
+            .draft
+            .map(|settings| Draft::install(settings, fd, initial, self.draft_resets));
+        let history = self.history.map(|settings| History::install(settings, fd));
 
         let (views, validation_cbs): (Vec<_>, Vec<_>) = self
             .render_fns
@@ -463,9 +964,14 @@ impl<FD: FormToolData> FormBuilder<FD> {
 
         let elements = fs.form_frame(ControlRenderData {
             data: views.into_view(),
+            id: self.ids.form_id(),
+            required: false,
             styles: self.styles,
         });
 
+        let submitted = store_value(None);
+        Self::on_success(saved.clone(), draft.clone(), submitted, action);
+
         let on_submit = move |ev: SubmitEvent| {
             if ev.default_prevented() {
                 return;
@@ -477,6 +983,7 @@ impl<FD: FormToolData> FormBuilder<FD> {
                 }
             }
             on_submit(ev, fd);
+            submitted.set_value(Some(fd.get_untracked()));
         };
 
         let view = view! {
@@ -488,6 +995,13 @@ impl<FD: FormToolData> FormBuilder<FD> {
         Form {
             fd,
             validations: self.validations,
+            warnings: self.warnings,
+            derivations: self.derivations,
+            change_fns: self.change_fns,
+            initial,
+            draft,
+            saved,
+            history,
             view,
         }
     }
@@ -502,6 +1016,15 @@ impl<FD: FormToolData> FormBuilder<FD> {
     ) -> Form<FD> {
         let fd = create_rw_signal(fd);
         let fs = Rc::new(fs);
+        for derivation in self.derivations.iter() {
+            (derivation.install)(fd);
+        }
+        let initial = create_rw_signal(fd.get_untracked());
+        let saved = self.unsaved_guard.as_ref().map(|guard| guard.install(fd));
+        let draft = self
+            .draft
+            .map(|settings| Draft::install(settings, fd, initial, self.draft_resets));
+        let history = self.history.map(|settings| History::install(settings, fd));
 
         let (views, validation_cbs): (Vec<_>, Vec<_>) = self
             .render_fns
@@ -511,9 +1034,12 @@ impl<FD: FormToolData> FormBuilder<FD> {
 
         let elements = fs.form_frame(ControlRenderData {
             data: views.into_view(),
+            id: self.ids.form_id(),
+            required: false,
             styles: self.styles,
         });
 
+        let submit_draft = draft.clone();
         let on_submit = move |ev: SubmitEvent| {
             if ev.default_prevented() {
                 return;
@@ -524,6 +1050,9 @@ impl<FD: FormToolData> FormBuilder<FD> {
                     return;
                 }
             }
+            if let Some(ref draft) = submit_draft {
+                draft.flush();
+            }
             on_submit(ev, fd);
         };
 
@@ -536,6 +1065,13 @@ impl<FD: FormToolData> FormBuilder<FD> {
         Form {
             fd,
             validations: self.validations,
+            warnings: self.warnings,
+            derivations: self.derivations,
+            change_fns: self.change_fns,
+            initial,
+            draft,
+            saved,
+            history,
             view,
         }
     }
@@ -544,6 +1080,15 @@ impl<FD: FormToolData> FormBuilder<FD> {
     pub(crate) fn build_form_controls(self, fd: FD, fs: FD::Style) -> Form<FD> {
         let fd = create_rw_signal(fd);
         let fs = Rc::new(fs);
+        for derivation in self.derivations.iter() {
+            (derivation.install)(fd);
+        }
+        let initial = create_rw_signal(fd.get_untracked());
+        let saved = self.unsaved_guard.as_ref().map(|guard| guard.install(fd));
+        let draft = self
+            .draft
+            .map(|settings| Draft::install(settings, fd, initial, self.draft_resets));
+        let history = self.history.map(|settings| History::install(settings, fd));
 
         let (views, _validation_cbs): (Vec<_>, Vec<_>) = self
             .render_fns
@@ -553,12 +1098,180 @@ impl<FD: FormToolData> FormBuilder<FD> {
 
         let view = fs.form_frame(ControlRenderData {
             data: views.into_view(),
+            id: self.ids.form_id(),
+            required: false,
+            styles: self.styles,
+        });
+
+        Form {
+            fd,
+            validations: self.validations,
+            warnings: self.warnings,
+            derivations: self.derivations,
+            change_fns: self.change_fns,
+            initial,
+            draft,
+            saved,
+            history,
+            view,
+        }
+    }
+
+    /// builds just the controls of the form, syncing the form data with the
+    /// query string.
+    pub(crate) fn build_query_form(
+        self,
+        fd: FD,
+        fs: FD::Style,
+        debounce: Option<Duration>,
+    ) -> Form<FD> {
+        let location = use_location();
+        let query = location.query;
+        let fields = Rc::new(self.query_fields);
+        let defaults = Rc::new(QueryField::defaults(&fields, &fd));
+        let initial = Rc::new(fd);
+
+        let fd = create_rw_signal(QueryField::read(&fields, &initial, &query.get_untracked()));
+        let fs = Rc::new(fs);
+        for derivation in self.derivations.iter() {
+            (derivation.install)(fd);
+        }
+        let initial_fd = create_rw_signal(fd.get_untracked());
+        let saved = self.unsaved_guard.as_ref().map(|guard| guard.install(fd));
+        let history = self.history.map(|settings| History::install(settings, fd));
+        let burst_delay = self.query_burst;
+
+        // the pending update of a debounced query string, and the end of the
+        // current burst of changes
+        let pending = store_value(None::<TimeoutHandle>);
+        let burst = store_value(None::<TimeoutHandle>);
+        // cancels the timeout, returning whether there was one
+        let cancel = |timeout: StoredValue<Option<TimeoutHandle>>| {
+            timeout
+                .try_update_value(Option::take)
+                .flatten()
+                .map(|handle| handle.clear())
+                .is_some()
+        };
+
+        // update the form data when the query string changes, like when
+        // going back or forward. Updates from before the change are dropped,
+        // so they don't overwrite it, and the next change adds a new history
+        // entry instead of replacing the one that was gone back to
+        let (read_fields, read_defaults) = (fields.clone(), defaults.clone());
+        create_effect(move |_| {
+            query.with(|query| {
+                let written = fd.with_untracked(|fd| {
+                    QueryField::write(&read_fields, &read_defaults, fd, query)
+                });
+                if written != *query {
+                    cancel(pending);
+                    cancel(burst);
+                    fd.set(QueryField::read(&read_fields, &initial, query));
+                }
+            });
+        });
+
+        // update the query string when the form data changes. The first
+        // change of a burst adds a history entry so the user can go back,
+        // and the rest of the burst replace it
+        let navigate = Rc::new(use_navigate());
+        let navigate = move |query: ParamsMap| {
+            let url = format!(
+                "{}{}{}",
+                location.pathname.get_untracked(),
+                query.to_query_string(),
+                location.hash.get_untracked(),
+            );
+            let options = NavigateOptions {
+                scroll: false,
+                replace: cancel(burst),
+                ..Default::default()
+            };
+            navigate(&url, options);
+            let end_burst = move || burst.set_value(None);
+            burst.set_value(set_timeout_with_handle(end_burst, burst_delay).ok());
+        };
+        create_effect(move |prev: Option<()>| {
+            let current = query.get_untracked();
+            let written = fd.with(|fd| QueryField::write(&fields, &defaults, fd, &current));
+            if prev.is_none() || written == current {
+                return;
+            }
+            match debounce {
+                Some(delay) => {
+                    cancel(pending);
+                    let navigate = navigate.clone();
+                    let update = move || {
+                        pending.set_value(None);
+                        navigate(written);
+                    };
+                    pending.set_value(set_timeout_with_handle(update, delay).ok());
+                }
+                None => navigate(written),
+            }
+        });
+
+        let (views, _validation_cbs): (Vec<_>, Vec<_>) = self
+            .render_fns
+            .into_iter()
+            .map(|r_fn| r_fn(fs.clone(), fd))
+            .unzip();
+
+        let view = fs.form_frame(ControlRenderData {
+            data: views.into_view(),
+            id: self.ids.form_id(),
+            required: false,
+            styles: self.styles,
+        });
+
+        Form {
+            fd,
+            validations: self.validations,
+            warnings: self.warnings,
+            derivations: self.derivations,
+            change_fns: self.change_fns,
+            initial: initial_fd,
+            draft: None,
+            saved,
+            history,
+            view,
+        }
+    }
+
+    /// builds the review of the form, showing the label and value of each
+    /// control.
+    pub(crate) fn build_review(self, fd: FD, fs: FD::Style) -> Form<FD> {
+        let fd = create_rw_signal(fd);
+        let fs = Rc::new(fs);
+        for derivation in self.derivations.iter() {
+            (derivation.install)(fd);
+        }
+        let initial = create_rw_signal(fd.get_untracked());
+
+        let views: Vec<_> = self
+            .review_fns
+            .into_iter()
+            .map(|r_fn| r_fn(fs.clone(), fd))
+            .collect();
+
+        let view = fs.form_frame(ControlRenderData {
+            data: views.into_view(),
+            id: self.ids.form_id(),
+            required: false,
             styles: self.styles,
         });
 
         Form {
             fd,
             validations: self.validations,
+            warnings: self.warnings,
+            derivations: self.derivations,
+            change_fns: self.change_fns,
+            initial,
+            draft: None,
+            saved: None,
+            history: None,
             view,
         }
     }
@@ -567,6 +1280,9 @@ impl<FD: FormToolData> FormBuilder<FD> {
     pub(crate) fn validator(&self) -> FormValidator<FD> {
         FormValidator {
             validations: self.validations.clone(),
+            warnings: self.warnings.clone(),
+            derivations: self.derivations.iter().map(|d| d.apply.clone()).collect(),
+            change_fns: self.change_fns.clone(),
         }
     }
 }
@@ -0,0 +1,210 @@
+use crate::{form::FormToolData, form_builder::FormBuilder};
+use leptos::*;
+use std::{cell::Cell, rc::Rc, time::Duration};
+use wasm_bindgen::{closure::Closure, JsCast};
+
+/// A function that checks if the form data has changed from the saved
+/// form data.
+pub(crate) trait ChangedFn<FD>: Fn(&FD, &FD) -> bool + 'static {}
+impl<FD, F> ChangedFn<FD> for F where F: Fn(&FD, &FD) -> bool + 'static {}
+
+/// The settings for blocking leaving the page while the form has unsaved
+/// changes.
+pub(crate) struct UnsavedGuard<FD> {
+    /// The message shown when asking the user if they want to leave.
+    message: Rc<str>,
+    changed: Rc<dyn ChangedFn<FD>>,
+}
+
+impl<FD> Clone for UnsavedGuard<FD> {
+    fn clone(&self) -> Self {
+        UnsavedGuard {
+            message: self.message.clone(),
+            changed: self.changed.clone(),
+        }
+    }
+}
+
+/// The saved state of a form with an [`UnsavedGuard`].
+pub(crate) struct SavedState<FD: 'static> {
+    /// The form data that was last saved, which starts as the initial form
+    /// data.
+    saved: RwSignal<FD>,
+    /// Whether the form data differs from the saved form data.
+    unsaved: Memo<bool>,
+    /// The message shown when asking the user if they want to leave.
+    message: Rc<str>,
+    /// Set when the user has already agreed to leave, so they aren't asked
+    /// twice when following a link that unloads the page.
+    leaving: Rc<Cell<bool>>,
+}
+
+impl<FD> Clone for SavedState<FD> {
+    fn clone(&self) -> Self {
+        SavedState {
+            saved: self.saved,
+            unsaved: self.unsaved,
+            message: self.message.clone(),
+            leaving: self.leaving.clone(),
+        }
+    }
+}
+
+impl<FD: 'static> SavedState<FD> {
+    /// Marks the given form data as saved.
+    pub(crate) fn mark_saved(&self, data: FD) {
+        self.saved.set(data);
+    }
+
+    /// Asks the user if they want to leave, if there are unsaved changes.
+    ///
+    /// Returns whether the user may leave.
+    pub(crate) fn confirm_leave(&self) -> bool {
+        if !self.unsaved.get_untracked() {
+            return true;
+        }
+        if !window().confirm_with_message(&self.message).unwrap_or(true) {
+            return false;
+        }
+
+        // the flag is only for the unload that follows right away, so
+        // navigating within the app doesn't skip the next question
+        self.leaving.set(true);
+        let leaving = self.leaving.clone();
+        set_timeout(move || leaving.set(false), Duration::ZERO);
+        true
+    }
+}
+
+impl<FD: Clone + 'static> UnsavedGuard<FD> {
+    /// Blocks leaving the page while the form data differs from the saved
+    /// form data.
+    ///
+    /// Returns the saved state, which starts as the initial form data.
+    pub(crate) fn install(&self, fd: RwSignal<FD>) -> SavedState<FD> {
+        let saved = create_rw_signal(fd.get_untracked());
+        let changed = self.changed.clone();
+        let unsaved = create_memo(move |_| fd.with(|fd| saved.with(|saved| changed(saved, fd))));
+        let state = SavedState {
+            saved,
+            unsaved,
+            message: self.message.clone(),
+            leaving: Rc::new(Cell::new(false)),
+        };
+
+        // effects only run in the browser, so the listeners are never added
+        // on the server
+        let effect_state = state.clone();
+        create_effect(move |_| {
+            let state = effect_state.clone();
+            let (leaving, message) = (state.leaving.clone(), state.message.clone());
+            let before_unload = window_event_listener(ev::beforeunload, move |ev| {
+                if unsaved.get_untracked() && !leaving.replace(false) {
+                    ev.prevent_default();
+                    ev.set_return_value(&message);
+                }
+            });
+
+            // the router navigates on clicks on links, so the click is
+            // checked in the capture phase, before the router sees it
+            let on_click = Closure::<dyn Fn(web_sys::MouseEvent)>::new(move |ev| {
+                if Self::is_navigation(&ev) && !state.confirm_leave() {
+                    ev.prevent_default();
+                }
+            });
+            let _ = window().add_event_listener_with_callback_and_bool(
+                "click",
+                on_click.as_ref().unchecked_ref(),
+                true,
+            );
+
+            on_cleanup(move || {
+                before_unload.remove();
+                let _ = window().remove_event_listener_with_callback_and_bool(
+                    "click",
+                    on_click.as_ref().unchecked_ref(),
+                    true,
+                );
+            });
+        });
+
+        state
+    }
+
+    /// Checks if the click is a plain click on a link that leaves the page.
+    fn is_navigation(ev: &web_sys::MouseEvent) -> bool {
+        if ev.default_prevented()
+            || ev.button() != 0
+            || ev.meta_key()
+            || ev.alt_key()
+            || ev.ctrl_key()
+            || ev.shift_key()
+        {
+            return false;
+        }
+
+        let Some(link) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            .and_then(|target| target.closest("a[href]").ok().flatten())
+        else {
+            return false;
+        };
+        // links that open somewhere else, or download something, don't leave
+        // the page
+        let target = link.get_attribute("target").unwrap_or_default();
+        if !(target.is_empty() || target == "_self") || link.has_attribute("download") {
+            return false;
+        }
+        !Self::is_same_document(&link)
+    }
+
+    /// Checks if the link only changes the hash of the current page, like
+    /// `href="#section"`, which scrolls instead of leaving the page.
+    fn is_same_document(link: &web_sys::Element) -> bool {
+        let location = window().location();
+        let (Some(href), Ok(current)) = (link.get_attribute("href"), location.href()) else {
+            return false;
+        };
+        let Ok(url) = web_sys::Url::new_with_base(&href, &current) else {
+            return false;
+        };
+        let Ok(current) = web_sys::Url::new(&current) else {
+            return false;
+        };
+        !url.hash().is_empty()
+            && url.origin() == current.origin()
+            && url.pathname() == current.pathname()
+            && url.search() == current.search()
+    }
+}
+
+impl<FD: FormToolData + PartialEq> FormBuilder<FD> {
+    /// Asks the user before they leave the page while the form has changes
+    /// that are not submitted.
+    ///
+    /// The form has unsaved changes while the form data differs from the
+    /// initial form data. This covers closing or reloading the tab and
+    /// following links, including the links handled by the leptos_router.
+    /// Links that only change the hash of the current page are not asked
+    /// about. The browser shows its own message when closing the tab, so
+    /// `message` is only shown when following links.
+    ///
+    /// The router can't cancel navigating with
+    /// [`use_navigate`](leptos_router::use_navigate) or with the browser's
+    /// back and forward buttons, so those are not covered. Before navigating
+    /// in code, use [`Form::confirm_leave`](crate::Form::confirm_leave) to
+    /// ask the user first.
+    ///
+    /// The changes are marked as saved after a successful submit with
+    /// [`get_form`](FormToolData::get_form) or
+    /// [`get_action_form`](FormToolData::get_action_form). For the other
+    /// forms, use [`Form::mark_saved`](crate::Form::mark_saved).
+    pub fn guard_unsaved_changes(mut self, message: impl ToString) -> Self {
+        self.unsaved_guard = Some(UnsavedGuard {
+            message: Rc::from(message.to_string()),
+            changed: Rc::new(|saved: &FD, fd: &FD| saved != fd),
+        });
+        self
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{form::FormToolData, form_builder::FormBuilder};
+use leptos::*;
+use leptos_dom::helpers::TimeoutHandle;
+use std::{collections::VecDeque, time::Duration};
+
+/// The settings for keeping the history of the form data.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct HistorySettings {
+    /// The most snapshots to keep for undoing.
+    limit: usize,
+    /// How long the form data must stop changing for before the next change
+    /// gets its own snapshot.
+    coalesce: Duration,
+}
+
+/// The undo and redo history of the form data.
+pub(crate) struct History<FD: 'static> {
+    fd: RwSignal<FD>,
+    /// The snapshots to undo to, the latest last.
+    past: RwSignal<VecDeque<FD>>,
+    /// The snapshots to redo to, the latest last.
+    future: RwSignal<Vec<FD>>,
+    /// Set while the form data is being set by undo or redo, so it isn't
+    /// recorded as a change.
+    traveling: StoredValue<bool>,
+    /// The timeout for the end of the current burst of changes, if there is
+    /// one.
+    burst: StoredValue<Option<TimeoutHandle>>,
+}
+
+impl<FD> Clone for History<FD> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<FD> Copy for History<FD> {}
+
+impl<FD: Clone + 'static> History<FD> {
+    /// Starts keeping the history of the form data signal.
+    pub(crate) fn install(settings: HistorySettings, fd: RwSignal<FD>) -> Self {
+        let history = History {
+            fd,
+            past: create_rw_signal(VecDeque::new()),
+            future: create_rw_signal(Vec::new()),
+            traveling: store_value(false),
+            burst: store_value(None),
+        };
+
+        // the effect returns the form data, so the next run has the form
+        // data from before the change
+        create_effect(move |prev: Option<FD>| {
+            let current = fd.get();
+            let Some(prev) = prev else {
+                return current;
+            };
+            if history.traveling.get_value() {
+                history.traveling.set_value(false);
+                return current;
+            }
+
+            // only the form data from before the first change of a burst is
+            // kept, so typing a word doesn't take a snapshot per letter
+            if history.burst.get_value().is_none() {
+                history.past.update(|past| {
+                    past.push_back(prev);
+                    if past.len() > settings.limit {
+                        past.pop_front();
+                    }
+                });
+            }
+            history.end_burst();
+            let handle =
+                set_timeout_with_handle(move || history.burst.set_value(None), settings.coalesce);
+            history.burst.set_value(handle.ok());
+
+            if history.future.with_untracked(|future| !future.is_empty()) {
+                history.future.update(Vec::clear);
+            }
+            current
+        });
+
+        history
+    }
+
+    /// Ends the current burst of changes, so the next change gets its own
+    /// snapshot.
+    fn end_burst(&self) {
+        if let Some(handle) = self.burst.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+    }
+
+    /// Sets the form data to the previous snapshot.
+    pub(crate) fn undo(&self) {
+        let Some(previous) = self.past.try_update(VecDeque::pop_back).flatten() else {
+            return;
+        };
+        let current = self.fd.get_untracked();
+        self.future.update(|future| future.push(current));
+        self.travel_to(previous);
+    }
+
+    /// Sets the form data to the snapshot that was last undone.
+    pub(crate) fn redo(&self) {
+        let Some(next) = self.future.try_update(Vec::pop).flatten() else {
+            return;
+        };
+        let current = self.fd.get_untracked();
+        self.past.update(|past| past.push_back(current));
+        self.travel_to(next);
+    }
+
+    /// Sets the form data without recording it as a change.
+    fn travel_to(&self, snapshot: FD) {
+        self.end_burst();
+        self.traveling.set_value(true);
+        self.fd.set(snapshot);
+    }
+
+    /// Gets a signal of whether there is anything to undo.
+    pub(crate) fn can_undo(&self) -> Signal<bool> {
+        let past = self.past;
+        Signal::derive(move || past.with(|past| !past.is_empty()))
+    }
+
+    /// Gets a signal of whether there is anything to redo.
+    pub(crate) fn can_redo(&self) -> Signal<bool> {
+        let future = self.future;
+        Signal::derive(move || future.with(|future| !future.is_empty()))
+    }
+}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Keeps a history of the form data, so changes to the whole form can be
+    /// undone with [`Form::undo`](crate::Form::undo) and redone with
+    /// [`Form::redo`](crate::Form::redo).
+    ///
+    /// Changes that happen less than `coalesce` apart, like typing a word,
+    /// are undone together. At most `limit` snapshots are kept, dropping the
+    /// oldest ones.
+    pub fn history(mut self, limit: usize, coalesce: Duration) -> Self {
+        self.history = Some(HistorySettings { limit, coalesce });
+        self
+    }
+}
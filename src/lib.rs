@@ -3,12 +3,22 @@
 //!
 //! To learn more, see the
 //! [README.md](https://github.com/MitchellMarinoDev/leptos_form_tool/blob/main/README.md)
+mod changes;
 pub mod controls;
+mod derive;
+mod draft;
 mod form;
 mod form_builder;
+mod guard;
+mod history;
+mod messages;
+mod query;
 pub mod styles;
 mod validation_builder;
 
+pub use changes::FieldChange;
+pub use draft::DraftStorage;
 pub use form::{Form, FormToolData, FormValidator};
 pub use form_builder::FormBuilder;
-pub use validation_builder::ValidationBuilder;
+pub use messages::{DefaultMessages, MessageCatalog, ValidationMessage};
+pub use validation_builder::{CharacterClass, FieldRules, NestedRules, ValidationBuilder};
@@ -0,0 +1,329 @@
+use crate::validation_builder::CharacterClass;
+use std::rc::Rc;
+
+/// A structured validation or parse error.
+///
+/// These are emitted by the rules of the
+/// [`ValidationBuilder`](crate::ValidationBuilder) and by the parse
+/// functions, and are turned into the text shown to the user by a
+/// [`MessageCatalog`].
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ValidationMessage {
+    /// The field is required, but has no value.
+    Required,
+    /// The field must be at least this many characters long.
+    MinLen(usize),
+    /// The field must be at most this many characters long.
+    MaxLen(usize),
+    /// The field must contain the given pattern.
+    Contains(String),
+    /// The field must start with the given prefix.
+    StartsWith(String),
+    /// The field must end with the given suffix.
+    EndsWith(String),
+    /// The field must match the given regular expression.
+    Pattern(String),
+    /// The field must be an email address.
+    Email,
+    /// The field must be a URL.
+    Url,
+    /// The field must only contain ASCII characters.
+    Ascii,
+    /// The field must only contain letters and numbers.
+    Alphanumeric,
+    /// The field must contain a character of the given class.
+    MissingCharacterClass(CharacterClass),
+    /// The field must contain characters from at least this many
+    /// character classes.
+    MinCharacterClasses(usize),
+    /// The field must be a number.
+    NotANumber,
+    /// The field must be at least the given value.
+    MinValue(String),
+    /// The field must be at most the given value.
+    MaxValue(String),
+    /// The field cannot be the given value.
+    NotAllowed(String),
+    /// The field must be equal to the field with the given name.
+    EqualsField(String),
+    /// The field cannot be equal to the field with the given name.
+    NotEqualField(String),
+    /// The field is required because the field with the given name is set.
+    RequiredIfSet(String),
+    /// The field must be greater than the field with the given name.
+    GreaterThanField(String),
+    /// The field must be less than the field with the given name.
+    LessThanField(String),
+    /// The field must be at least the field with the given name.
+    AtLeastField(String),
+    /// The field must be at most the field with the given name.
+    AtMostField(String),
+    /// The field must have at least this many items.
+    MinItems(usize),
+    /// The field must have at most this many items.
+    MaxItems(usize),
+    /// The item at this index is a duplicate of an earlier item.
+    Duplicate(usize),
+    /// The item at `index` failed validation with `message`.
+    Item {
+        /// The index of the item, starting at 0.
+        index: usize,
+        /// Why the item failed validation.
+        message: Box<ValidationMessage>,
+    },
+    /// The value could not be parsed, and there is no more specific message
+    /// for why, like [`NotANumber`](Self::NotANumber).
+    ///
+    /// This holds the error text of the parsing function, like the
+    /// [`FromStr`](std::str::FromStr) error message, which is not localized.
+    ParseFailed(String),
+    /// A custom message that is shown as is.
+    Custom(String),
+}
+
+impl ValidationMessage {
+    /// Gets the error code for this message.
+    ///
+    /// This is a stable identifier, useful as a key into translation files.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValidationMessage::Required => "required",
+            ValidationMessage::MinLen(_) => "min_len",
+            ValidationMessage::MaxLen(_) => "max_len",
+            ValidationMessage::Contains(_) => "contains",
+            ValidationMessage::StartsWith(_) => "starts_with",
+            ValidationMessage::EndsWith(_) => "ends_with",
+            ValidationMessage::Pattern(_) => "pattern",
+            ValidationMessage::Email => "email",
+            ValidationMessage::Url => "url",
+            ValidationMessage::Ascii => "ascii",
+            ValidationMessage::Alphanumeric => "alphanumeric",
+            ValidationMessage::MissingCharacterClass(_) => "missing_character_class",
+            ValidationMessage::MinCharacterClasses(_) => "min_character_classes",
+            ValidationMessage::NotANumber => "not_a_number",
+            ValidationMessage::MinValue(_) => "min_value",
+            ValidationMessage::MaxValue(_) => "max_value",
+            ValidationMessage::NotAllowed(_) => "not_allowed",
+            ValidationMessage::EqualsField(_) => "equals_field",
+            ValidationMessage::NotEqualField(_) => "not_equal_field",
+            ValidationMessage::RequiredIfSet(_) => "required_if_set",
+            ValidationMessage::GreaterThanField(_) => "greater_than_field",
+            ValidationMessage::LessThanField(_) => "less_than_field",
+            ValidationMessage::AtLeastField(_) => "at_least_field",
+            ValidationMessage::AtMostField(_) => "at_most_field",
+            ValidationMessage::MinItems(_) => "min_items",
+            ValidationMessage::MaxItems(_) => "max_items",
+            ValidationMessage::Duplicate(_) => "duplicate",
+            ValidationMessage::Item { .. } => "item",
+            ValidationMessage::ParseFailed(_) => "parse_failed",
+            ValidationMessage::Custom(_) => "custom",
+        }
+    }
+}
+
+/// Renders [`ValidationMessage`]s into the text shown to the user.
+///
+/// Implement this to translate the error messages into the user's locale.
+/// A catalog can be set on the
+/// [`ValidationBuilder`](crate::ValidationBuilder::messages) and on the
+/// [`FormBuilder`](crate::FormBuilder::messages), which uses it for parse
+/// errors.
+pub trait MessageCatalog: 'static {
+    /// Renders the message for the field with the given name.
+    ///
+    /// For parse errors, `field` is the label of the control, or its name if
+    /// it has no label.
+    fn message(&self, field: &str, message: &ValidationMessage) -> String;
+}
+
+impl<M: MessageCatalog + ?Sized> MessageCatalog for Rc<M> {
+    fn message(&self, field: &str, message: &ValidationMessage) -> String {
+        (**self).message(field, message)
+    }
+}
+
+/// The default, english, [`MessageCatalog`].
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DefaultMessages;
+
+impl MessageCatalog for DefaultMessages {
+    fn message(&self, field: &str, message: &ValidationMessage) -> String {
+        match message {
+            ValidationMessage::Required => format!("{} is required", field),
+            ValidationMessage::MinLen(min_len) => {
+                format!("{} must be >= {} characters", field, min_len)
+            }
+            ValidationMessage::MaxLen(max_len) => {
+                format!("{} must be <= {} characters", field, max_len)
+            }
+            ValidationMessage::Contains(pattern) => format!("{} must contain {}", field, pattern),
+            ValidationMessage::StartsWith(prefix) => {
+                format!("{} must start with {}", field, prefix)
+            }
+            ValidationMessage::EndsWith(suffix) => format!("{} must end with {}", field, suffix),
+            ValidationMessage::Pattern(_) => format!("{} is not in the right format", field),
+            ValidationMessage::Email => format!("{} must be an email address", field),
+            ValidationMessage::Url => format!("{} must be a URL", field),
+            ValidationMessage::Ascii => format!("{} must only contain ASCII characters", field),
+            ValidationMessage::Alphanumeric => {
+                format!("{} must only contain letters and numbers", field)
+            }
+            ValidationMessage::MissingCharacterClass(class) => {
+                let class = match class {
+                    CharacterClass::Lowercase => "a lowercase letter",
+                    CharacterClass::Uppercase => "an uppercase letter",
+                    CharacterClass::Digit => "a digit",
+                    CharacterClass::Symbol => "a symbol",
+                };
+                format!("{} must contain {}", field, class)
+            }
+            ValidationMessage::MinCharacterClasses(min_classes) => format!(
+                "{} must contain at least {} of lowercase letters, uppercase letters, digits and symbols",
+                field, min_classes
+            ),
+            ValidationMessage::NotANumber => format!("{} must be a number", field),
+            ValidationMessage::MinValue(min_value) => format!("{} must be >= {}", field, min_value),
+            ValidationMessage::MaxValue(max_value) => format!("{} must be <= {}", field, max_value),
+            ValidationMessage::NotAllowed(value) => format!("{} cannot be {}", field, value),
+            ValidationMessage::EqualsField(other) => format!("{} must match {}", field, other),
+            ValidationMessage::NotEqualField(other) => {
+                format!("{} must be different from {}", field, other)
+            }
+            ValidationMessage::RequiredIfSet(other) => {
+                format!("{} is required when {} is set", field, other)
+            }
+            ValidationMessage::GreaterThanField(other) => {
+                format!("{} must be greater than {}", field, other)
+            }
+            ValidationMessage::LessThanField(other) => {
+                format!("{} must be less than {}", field, other)
+            }
+            ValidationMessage::AtLeastField(other) => {
+                format!("{} must be at least {}", field, other)
+            }
+            ValidationMessage::AtMostField(other) => format!("{} must be at most {}", field, other),
+            ValidationMessage::MinItems(min_items) => {
+                format!("{} must have at least {} items", field, min_items)
+            }
+            ValidationMessage::MaxItems(max_items) => {
+                format!("{} must have at most {} items", field, max_items)
+            }
+            ValidationMessage::Duplicate(index) => {
+                format!("{} item {} is a duplicate", field, index + 1)
+            }
+            ValidationMessage::Item { index, message } => {
+                format!("{} (item {})", self.message(field, message), index + 1)
+            }
+            ValidationMessage::ParseFailed(detail) => detail.clone(),
+            ValidationMessage::Custom(msg) => msg.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controls::parse_error_message;
+
+    struct Shouting;
+
+    impl MessageCatalog for Shouting {
+        fn message(&self, field: &str, message: &ValidationMessage) -> String {
+            format!(
+                "{}: {}",
+                field.to_uppercase(),
+                message.code().to_uppercase()
+            )
+        }
+    }
+
+    #[test]
+    fn default_text() {
+        let text = |message| DefaultMessages.message("Name", &message);
+        assert_eq!(text(ValidationMessage::Required), "Name is required");
+        assert_eq!(
+            text(ValidationMessage::MinLen(3)),
+            "Name must be >= 3 characters"
+        );
+        assert_eq!(
+            text(ValidationMessage::MissingCharacterClass(
+                CharacterClass::Digit
+            )),
+            "Name must contain a digit"
+        );
+        assert_eq!(
+            text(ValidationMessage::EqualsField(String::from("Password"))),
+            "Name must match Password"
+        );
+        assert_eq!(
+            text(ValidationMessage::Duplicate(0)),
+            "Name item 1 is a duplicate"
+        );
+        assert_eq!(
+            text(ValidationMessage::ParseFailed(String::from("bad value"))),
+            "bad value"
+        );
+        assert_eq!(
+            text(ValidationMessage::Custom(String::from("as is"))),
+            "as is"
+        );
+    }
+
+    #[test]
+    fn default_text_for_items() {
+        let message = ValidationMessage::Item {
+            index: 2,
+            message: Box::new(ValidationMessage::MaxLen(5)),
+        };
+        assert_eq!(
+            DefaultMessages.message("Tags", &message),
+            "Tags must be <= 5 characters (item 3)"
+        );
+    }
+
+    #[test]
+    fn codes() {
+        assert_eq!(ValidationMessage::Required.code(), "required");
+        assert_eq!(ValidationMessage::NotANumber.code(), "not_a_number");
+        assert_eq!(
+            ValidationMessage::RequiredIfSet(String::new()).code(),
+            "required_if_set"
+        );
+        assert_eq!(
+            ValidationMessage::Item {
+                index: 0,
+                message: Box::new(ValidationMessage::Email),
+            }
+            .code(),
+            "item"
+        );
+    }
+
+    #[test]
+    fn custom_catalog() {
+        let catalog: Rc<dyn MessageCatalog> = Rc::new(Shouting);
+        assert_eq!(
+            catalog.message("Age", &ValidationMessage::NotANumber),
+            "AGE: NOT_A_NUMBER"
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_error_message("x".parse::<u32>().unwrap_err()),
+            ValidationMessage::NotANumber
+        );
+        assert_eq!(
+            parse_error_message("x".parse::<f64>().unwrap_err()),
+            ValidationMessage::NotANumber
+        );
+        assert_eq!(
+            parse_error_message("x".parse::<bool>().unwrap_err()),
+            ValidationMessage::ParseFailed(String::from(
+                "provided string was not `true` or `false`"
+            ))
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{
+    controls::{ControlData, ControlRenderData, FieldGetter, FieldSetter, ParseFn, UnparseFn},
+    form::FormToolData,
+};
+use leptos_router::ParamsMap;
+use std::rc::Rc;
+
+/// A function that gets a control's value for the query string.
+pub(crate) trait ToQueryFn<FD>: Fn(&FD) -> Option<String> + 'static {}
+impl<FD, F> ToQueryFn<FD> for F where F: Fn(&FD) -> Option<String> + 'static {}
+/// A function that sets a control's field from its value in the query
+/// string.
+pub(crate) trait FromQueryFn<FD>: Fn(&mut FD, &str) + 'static {}
+impl<FD, F> FromQueryFn<FD> for F where F: Fn(&mut FD, &str) + 'static {}
+
+/// A control that can be synced to the query string.
+pub(crate) struct QueryField<FD> {
+    /// The key of the control's value in the query string.
+    name: String,
+    to_query: Rc<dyn ToQueryFn<FD>>,
+    from_query: Rc<dyn FromQueryFn<FD>>,
+}
+
+impl<FD> Clone for QueryField<FD> {
+    fn clone(&self) -> Self {
+        QueryField {
+            name: self.name.clone(),
+            to_query: self.to_query.clone(),
+            from_query: self.from_query.clone(),
+        }
+    }
+}
+
+impl<FD: FormToolData> QueryField<FD> {
+    /// Creates the [`QueryField`] for a control, if it has a name.
+    ///
+    /// The value in the query string goes through the control's parse
+    /// functions, just like the user's input does.
+    pub(crate) fn new<C: ControlData<FD>, FDT: 'static>(
+        render_data: Rc<ControlRenderData<FD::Style, C>>,
+        getter: Rc<dyn FieldGetter<FD, FDT>>,
+        setter: Rc<dyn FieldSetter<FD, FDT>>,
+        parse_fn: Rc<dyn ParseFn<C::ReturnType, FDT>>,
+        unparse_fn: Rc<dyn UnparseFn<C::ReturnType, FDT>>,
+    ) -> Option<Self> {
+        let name = render_data
+            .data
+            .name()
+            .filter(|name| !name.is_empty())?
+            .to_string();
+
+        let to_query_data = render_data.clone();
+        let to_query = move |fd: &FD| (to_query_data.data).value_to_string(&unparse_fn(getter(fd)));
+        // values that aren't valid are ignored, leaving the field as is
+        let from_query = move |fd: &mut FD, value: &str| {
+            let parsed = (render_data.data)
+                .value_from_string(value)
+                .and_then(|v| parse_fn(v).ok());
+            if let Some(parsed) = parsed {
+                setter(fd, parsed);
+            }
+        };
+
+        Some(QueryField {
+            name,
+            to_query: Rc::new(to_query),
+            from_query: Rc::new(from_query),
+        })
+    }
+
+    /// Gets the values of the fields in the `initial` form data, which are
+    /// left out of the query string.
+    pub(crate) fn defaults(fields: &[QueryField<FD>], initial: &FD) -> Vec<Option<String>> {
+        fields.iter().map(|f| (f.to_query)(initial)).collect()
+    }
+
+    /// Reads the form data from the query string.
+    ///
+    /// Fields that are not in the query string get their value from the
+    /// `initial` form data.
+    pub(crate) fn read(fields: &[QueryField<FD>], initial: &FD, query: &ParamsMap) -> FD {
+        let mut fd = initial.clone();
+        for field in fields {
+            if let Some(value) = query.get(&field.name) {
+                (field.from_query)(&mut fd, value);
+            }
+        }
+        fd
+    }
+
+    /// Writes the form data into the query string.
+    ///
+    /// The parameters of the query string that don't belong to the form are
+    /// kept, and fields that have their default value are left out.
+    pub(crate) fn write(
+        fields: &[QueryField<FD>],
+        defaults: &[Option<String>],
+        fd: &FD,
+        query: &ParamsMap,
+    ) -> ParamsMap {
+        let mut query = query.clone();
+        for (field, default) in fields.iter().zip(defaults) {
+            match (field.to_query)(fd) {
+                Some(value) if Some(&value) != default.as_ref() => {
+                    query.insert(field.name.clone(), value);
+                }
+                _ => {
+                    query.remove(&field.name);
+                }
+            }
+        }
+        query
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{styles::GridFormStyle, FormBuilder};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Search {
+        q: String,
+        page: u32,
+    }
+
+    impl Default for Search {
+        fn default() -> Self {
+            Search {
+                q: String::new(),
+                page: 1,
+            }
+        }
+    }
+
+    impl FormToolData for Search {
+        type Style = GridFormStyle;
+        type Context = ();
+
+        fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+            fb.text_input(|c| {
+                c.named("q")
+                    .getter(|fd| fd.q.clone())
+                    .setter(|fd, q| fd.q = q)
+                    .parse_string()
+            })
+            .text_input(|c| {
+                c.named("page")
+                    .getter(|fd| fd.page)
+                    .setter(|fd, page| fd.page = page)
+                    .parse_trimmed()
+            })
+            // controls without a name are not synced
+            .text_input(|c| {
+                c.getter(|fd| fd.q.clone())
+                    .setter(|fd, q| fd.q = q)
+                    .parse_string()
+            })
+        }
+    }
+
+    fn fields() -> Vec<QueryField<Search>> {
+        Search::build_form(FormBuilder::new_unrendered(())).query_fields
+    }
+
+    fn params(pairs: &[(&str, &str)]) -> ParamsMap {
+        let mut query = ParamsMap::new();
+        for (key, value) in pairs {
+            query.insert(key.to_string(), value.to_string());
+        }
+        query
+    }
+
+    #[test]
+    fn unnamed_controls_skipped() {
+        let names: Vec<_> = fields().iter().map(|f| f.name.clone()).collect();
+        assert_eq!(names, ["q", "page"]);
+    }
+
+    #[test]
+    fn round_trip() {
+        let fields = fields();
+        let initial = Search::default();
+        let defaults = QueryField::defaults(&fields, &initial);
+
+        let fd = Search {
+            q: String::from("rust forms"),
+            page: 3,
+        };
+        let query = QueryField::write(&fields, &defaults, &fd, &ParamsMap::new());
+        assert_eq!(query, params(&[("q", "rust forms"), ("page", "3")]));
+        assert_eq!(QueryField::read(&fields, &initial, &query), fd);
+    }
+
+    #[test]
+    fn defaults_left_out() {
+        let fields = fields();
+        let initial = Search::default();
+        let defaults = QueryField::defaults(&fields, &initial);
+        assert_eq!(defaults, [Some(String::new()), Some(String::from("1"))]);
+
+        let fd = Search {
+            page: 2,
+            ..Search::default()
+        };
+        let query = params(&[("q", "old"), ("page", "5"), ("other", "kept")]);
+        assert_eq!(
+            QueryField::write(&fields, &defaults, &fd, &query),
+            params(&[("page", "2"), ("other", "kept")])
+        );
+    }
+
+    #[test]
+    fn missing_and_invalid_params() {
+        let fields = fields();
+        let initial = Search {
+            q: String::from("initial"),
+            page: 4,
+        };
+        assert_eq!(
+            QueryField::read(&fields, &initial, &ParamsMap::new()),
+            initial
+        );
+        assert_eq!(
+            QueryField::read(&fields, &initial, &params(&[("page", "abc")])),
+            initial
+        );
+        assert_eq!(
+            QueryField::read(&fields, &initial, &params(&[("q", "new"), ("page", " 7 ")])),
+            Search {
+                q: String::from("new"),
+                page: 7,
+            }
+        );
+    }
+}
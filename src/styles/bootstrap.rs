@@ -0,0 +1,894 @@
+use super::{error_id, help_id, Breakpoint, FormStyle};
+use crate::controls::{
+    button::ButtonData,
+    checkbox::CheckboxData,
+    date::DateData,
+    heading::HeadingData,
+    hidden::HiddenData,
+    output::OutputData,
+    password::{PasswordData, PasswordStrength},
+    radio_buttons::RadioButtonsData,
+    select::SelectData,
+    slider::SliderData,
+    spacer::SpacerData,
+    stepper::StepperData,
+    submit::SubmitData,
+    switch::SwitchData,
+    text_area::TextAreaData,
+    text_input::TextInputData,
+    ControlRenderData, UpdateEvent, ValidationState,
+};
+use leptos::*;
+use std::rc::Rc;
+use web_sys::MouseEvent;
+
+/// The size of a bootstrap control.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BsSize {
+    /// The small variant (`form-control-sm`, `btn-sm`, ...).
+    Small,
+    /// The large variant (`form-control-lg`, `btn-lg`, ...).
+    Large,
+}
+
+impl BsSize {
+    fn suffix(&self) -> &'static str {
+        match self {
+            BsSize::Small => "sm",
+            BsSize::Large => "lg",
+        }
+    }
+}
+
+/// Styling attributes for the [`BootstrapFormStyle`].
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BsStyleAttr {
+    /// Set the width of the control out of 12.
+    /// Defaults to 12/12 (full width).
+    Width(u32),
+    /// Set the width of the control out of 12, from the given breakpoint
+    /// and up.
+    ///
+    /// This maps to bootstrap's `col-{breakpoint}-{width}` classes.
+    WidthAt(Breakpoint, u32),
+    /// Adds a tooltip to the control.
+    /// This sets the html title attribute, which shows the text when the
+    /// user hovers their mouse over the control for a couple seconds.
+    Tooltip(String),
+    /// Renders the label inside the control using bootstrap's floating
+    /// labels.
+    ///
+    /// This is only respected by text inputs, text areas and selects.
+    FloatingLabel,
+    /// Sets the size of the control.
+    Size(BsSize),
+    /// Adds help text underneath the control.
+    HelpText(String),
+}
+
+/// A form style for [Bootstrap 5](https://getbootstrap.com/).
+///
+/// This style only emits bootstrap's class names, so bootstrap's css needs
+/// to be included in your page for it to look right.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BootstrapFormStyle;
+
+impl BootstrapFormStyle {
+    /// Gets the column classes for the given styles.
+    fn col_class(styles: &[BsStyleAttr]) -> String {
+        let mut width = 12;
+        let mut breakpoint_widths = Vec::new();
+        for style in styles.iter() {
+            match style {
+                BsStyleAttr::Width(w) => width = *w,
+                BsStyleAttr::WidthAt(bp, w) => breakpoint_widths.push((*bp, *w)),
+                _ => {}
+            }
+        }
+
+        let mut class = format!("col-{}", width);
+        for (bp, w) in breakpoint_widths {
+            class.push_str(&format!(" col-{}-{}", bp.bootstrap_infix(), w));
+        }
+        class
+    }
+
+    /// Gets the size, if one was set.
+    fn size(styles: &[BsStyleAttr]) -> Option<BsSize> {
+        styles.iter().rev().find_map(|style| match style {
+            BsStyleAttr::Size(size) => Some(*size),
+            _ => None,
+        })
+    }
+
+    /// Returns true if the floating label attribute was set.
+    fn is_floating(styles: &[BsStyleAttr]) -> bool {
+        styles
+            .iter()
+            .any(|style| matches!(style, BsStyleAttr::FloatingLabel))
+    }
+
+    /// Gets the class for a input with the given base class, like
+    /// `form-control` or `form-select`.
+    fn sized_class(base: &'static str, styles: &[BsStyleAttr]) -> String {
+        match Self::size(styles) {
+            Some(size) => format!("{} {}-{}", base, base, size.suffix()),
+            None => base.to_string(),
+        }
+    }
+
+    /// Renders the help text, if there is any.
+    fn help_text(styles: &[BsStyleAttr], id: &str) -> Option<View> {
+        styles.iter().rev().find_map(|style| match style {
+            BsStyleAttr::HelpText(text) => Some(
+                view! {
+                    <div class="form-text" id=help_id(id)>
+                        {text.clone()}
+                    </div>
+                }
+                .into_view(),
+            ),
+            _ => None,
+        })
+    }
+
+    /// Gets the ids of the elements that describe the control with the
+    /// given id, for use in `aria-describedby`.
+    fn described_by(styles: &[BsStyleAttr], id: &str) -> String {
+        let mut described_by = error_id(id);
+        if styles
+            .iter()
+            .any(|style| matches!(style, BsStyleAttr::HelpText(_)))
+        {
+            described_by.push(' ');
+            described_by.push_str(&help_id(id));
+        }
+        described_by
+    }
+
+    /// Gets the class for the feedback element. Warnings are always shown
+    /// as bootstrap only shows `invalid-feedback` next to invalid inputs.
+    fn feedback_class(validation_state: Signal<ValidationState>) -> &'static str {
+        match validation_state.get().is_warning() {
+            true => "form-text text-warning",
+            false => "invalid-feedback",
+        }
+    }
+
+    /// Renders the feedback for the validation state.
+    fn feedback(id: &str, validation_state: Signal<ValidationState>) -> View {
+        view! {
+            <div
+                class=move || Self::feedback_class(validation_state)
+                id=error_id(id)
+                aria-live="polite"
+            >
+                {move || validation_state.get().take_msg()}
+            </div>
+        }
+        .into_view()
+    }
+
+    fn common_component(
+        &self,
+        styles: &[<BootstrapFormStyle as FormStyle>::StylingAttributes],
+        parent_class: &'static str,
+        inner: View,
+    ) -> View {
+        let mut tooltip = None;
+        for style in styles.iter() {
+            if let BsStyleAttr::Tooltip(t) = style {
+                tooltip = Some(t.clone());
+            }
+        }
+
+        let class = format!("{} {}", Self::col_class(styles), parent_class);
+
+        view! {
+            <div class=class title=tooltip>
+                {inner}
+            </div>
+        }
+        .into_view()
+    }
+
+    /// Lays out a label, control and feedback, respecting the floating label
+    /// attribute.
+    fn labeled_control(
+        styles: &[BsStyleAttr],
+        id: &str,
+        label: Option<&String>,
+        control: View,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let label = label.cloned();
+        if Self::is_floating(styles) {
+            view! {
+                <div class="form-floating">
+                    {control}
+                    <label for=id.to_string()>{label}</label>
+                    {Self::feedback(id, validation_state)}
+                </div>
+                {Self::help_text(styles, id)}
+            }
+            .into_view()
+        } else {
+            view! {
+                <label for=id.to_string() class="form-label">
+                    {label}
+                </label>
+                {control}
+                {Self::feedback(id, validation_state)}
+                {Self::help_text(styles, id)}
+            }
+            .into_view()
+        }
+    }
+}
+
+impl FormStyle for BootstrapFormStyle {
+    type StylingAttributes = BsStyleAttr;
+
+    fn form_frame(&self, form: ControlRenderData<Self, View>) -> View {
+        view! { <div class="row g-3">{form.data}</div> }.into_view()
+    }
+
+    /// A common function that wraps the given view in the styles
+    fn custom_component(&self, styles: &[Self::StylingAttributes], inner: View) -> View {
+        self.common_component(styles, "", inner)
+    }
+
+    fn group(&self, group: Rc<ControlRenderData<Self, View>>) -> View {
+        let view =
+            view! { <div class="row g-3 border rounded p-3">{&group.data}</div> }.into_view();
+
+        self.common_component(&group.styles, "", view)
+    }
+
+    fn spacer(&self, control: Rc<ControlRenderData<Self, SpacerData>>) -> View {
+        self.common_component(
+            &control.styles,
+            "",
+            view! { <div style:height=control.data.height.as_ref()></div> }.into_view(),
+        )
+    }
+
+    fn heading(
+        &self,
+        control: Rc<ControlRenderData<Self, HeadingData>>,
+        value_getter: Option<Signal<String>>,
+    ) -> View {
+        use crate::controls::heading::HeadingLevel::*;
+
+        let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
+
+        let view = match control.data.level {
+            H1 => view! { <h1> {title} </h1> }.into_view(),
+            H2 => view! { <h2> {title} </h2> }.into_view(),
+            H3 => view! { <h3> {title} </h3> }.into_view(),
+            H4 => view! { <h4> {title} </h4> }.into_view(),
+        };
+
+        self.common_component(&control.styles, "", view)
+    }
+
+    fn submit(
+        &self,
+        control: Rc<ControlRenderData<Self, SubmitData>>,
+        value_getter: Option<Signal<String>>,
+    ) -> View {
+        let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
+        let class = Self::sized_class("btn", &control.styles) + " btn-primary";
+
+        self.common_component(
+            &control.styles,
+            "",
+            view! { <input type="submit" value=title class=class/> }.into_view(),
+        )
+    }
+
+    fn button(
+        &self,
+        control: Rc<ControlRenderData<Self, ButtonData>>,
+        value_getter: Option<Signal<String>>,
+    ) -> View {
+        let action = control.data.action.clone();
+        let on_click = move |ev: MouseEvent| {
+            if let Some(ref action) = action {
+                action(ev)
+            }
+        };
+
+        let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
+        let class = Self::sized_class("btn", &control.styles) + " btn-secondary";
+
+        let view = view! {
+            <button type="button" class=class on:click=on_click>
+                {title}
+            </button>
+        }
+        .into_view();
+
+        self.common_component(&control.styles, "", view)
+    }
+
+    fn output(
+        &self,
+        control: Rc<ControlRenderData<Self, OutputData>>,
+        value_getter: Option<Signal<String>>,
+    ) -> View {
+        let view = view! {
+            <p class="form-control-plaintext">{move || value_getter.map(|g| g.get())}</p>
+        }
+        .into_view();
+        self.common_component(&control.styles, "", view)
+    }
+
+    fn review_item(
+        &self,
+        styles: &[Self::StylingAttributes],
+        label: String,
+        value: Signal<String>,
+    ) -> View {
+        let view = view! {
+            <dl class="mb-0">
+                <dt class="form-label">{label}</dt>
+                <dd class="form-control-plaintext">{value}</dd>
+            </dl>
+        }
+        .into_view();
+        self.common_component(styles, "", view)
+    }
+
+    fn hidden(
+        &self,
+        control: Rc<ControlRenderData<Self, HiddenData>>,
+        value_getter: Option<Signal<String>>,
+    ) -> View {
+        let value_getter = move || value_getter.map(|g| g.get());
+        view! { <input type="hidden" name=&control.data.name prop:value=value_getter/> }.into_view()
+    }
+
+    fn text_input(
+        &self,
+        control: Rc<ControlRenderData<Self, TextInputData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        // floating labels need a placeholder to work
+        let placeholder = match Self::is_floating(&control.styles) {
+            true => control
+                .data
+                .placeholder
+                .clone()
+                .or(control.data.label.clone())
+                .or(Some(String::from(" "))),
+            false => control.data.placeholder.clone(),
+        };
+
+        let input = view! {
+            <input
+                type=control.data.input_type
+                id=&control.id
+                name=&control.data.name
+                placeholder=placeholder
+                class=Self::sized_class("form-control", &control.styles)
+                class=("is-invalid", move || validation_state.get().is_err())
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &control.id)
+                prop:value=move || value_getter.get()
+            />
+        };
+
+        let input = match control.data.update_event {
+            UpdateEvent::OnFocusout => input.on(ev::focusout, move |ev| {
+                value_setter.set(event_target_value(&ev));
+            }),
+            UpdateEvent::OnInput => input.on(ev::input, move |ev| {
+                value_setter.set(event_target_value(&ev));
+            }),
+            UpdateEvent::OnChange => input.on(ev::change, move |ev| {
+                value_setter.set(event_target_value(&ev));
+            }),
+        };
+
+        let view = Self::labeled_control(
+            &control.styles,
+            &control.id,
+            control.data.label.as_ref(),
+            input.into_view(),
+            validation_state,
+        );
+
+        self.common_component(&control.styles, "", view)
+    }
+
+    fn password(
+        &self,
+        control: Rc<ControlRenderData<Self, PasswordData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let shown = create_rw_signal(false);
+
+        let data = control.clone();
+        let toggle = control.data.reveal_labels.is_some().then(|| {
+            view! {
+                <button
+                    type="button"
+                    class="btn btn-outline-secondary"
+                    aria-controls=&control.id
+                    aria-pressed=move || shown.get().to_string()
+                    on:click=move |_| shown.update(|shown| *shown = !*shown)
+                >
+                    {move || data.data.reveal_label(shown.get()).map(String::from)}
+                </button>
+            }
+        });
+
+        let data = control.clone();
+        let strength = Signal::derive(move || data.data.strength(&value_getter.get()));
+        let meter = control.data.strength.is_some().then(|| {
+            let bar_class = move || {
+                let color = match strength.get().map(|s| s.score).unwrap_or_default() {
+                    0 | 1 => "bg-danger",
+                    2 => "bg-warning",
+                    3 => "bg-info",
+                    _ => "bg-success",
+                };
+                format!("progress-bar {}", color)
+            };
+            view! {
+                <div
+                    class="progress mt-2"
+                    style="height: 0.5rem"
+                    role="meter"
+                    aria-valuemin="0"
+                    aria-valuemax=PasswordStrength::MAX_SCORE
+                    aria-valuenow=move || strength.get().map(|s| s.score).unwrap_or_default()
+                >
+                    <div
+                        class=bar_class
+                        style:width=move || {
+                            format!("{}%", strength.get().map(|s| s.percent()).unwrap_or_default())
+                        }
+                    ></div>
+                </div>
+                <div class="form-text" aria-live="polite">
+                    {move || strength.get().map(|s| s.label)}
+                </div>
+            }
+        });
+
+        // the feedback has to be in the input group for bootstrap to show it
+        let group_class = format!(
+            "{} has-validation",
+            Self::sized_class("input-group", &control.styles)
+        );
+        let view = view! {
+            <label for=&control.id class="form-label">
+                {control.data.label.as_ref()}
+            </label>
+            <div class=group_class>
+                <input
+                    type=move || if shown.get() { "text" } else { "password" }
+                    id=&control.id
+                    name=&control.data.name
+                    placeholder=control.data.placeholder.as_ref()
+                    autocomplete=control.data.autocomplete
+                    class="form-control"
+                    class=("is-invalid", move || validation_state.get().is_err())
+                    aria-invalid=move || validation_state.get().is_err().to_string()
+                    aria-required=control.required.then_some("true")
+                    aria-describedby=Self::described_by(&control.styles, &control.id)
+                    prop:value=move || value_getter.get()
+                    on:input=move |ev| {
+                        value_setter.set(event_target_value(&ev));
+                    }
+                />
+                {toggle}
+                {Self::feedback(&control.id, validation_state)}
+            </div>
+            {meter}
+            {Self::help_text(&control.styles, &control.id)}
+        }
+        .into_view();
+
+        self.common_component(&control.styles, "", view)
+    }
+
+    fn text_area(
+        &self,
+        control: Rc<ControlRenderData<Self, TextAreaData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        // floating labels need a placeholder to work
+        let placeholder = match Self::is_floating(&control.styles) {
+            true => control
+                .data
+                .placeholder
+                .clone()
+                .or(control.data.label.clone())
+                .or(Some(String::from(" "))),
+            false => control.data.placeholder.clone(),
+        };
+
+        let input = view! {
+            <textarea
+                id=&control.id
+                name=&control.data.name
+                placeholder=placeholder
+                prop:value=move || value_getter.get()
+                style="resize: vertical;"
+                class=Self::sized_class("form-control", &control.styles)
+                class=("is-invalid", move || validation_state.get().is_err())
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &control.id)
+            ></textarea>
+        };
+
+        let input = match control.data.update_event {
+            UpdateEvent::OnFocusout => input.on(ev::focusout, move |ev| {
+                value_setter.set(event_target_value(&ev));
+            }),
+            UpdateEvent::OnInput => input.on(ev::input, move |ev| {
+                value_setter.set(event_target_value(&ev));
+            }),
+            UpdateEvent::OnChange => input.on(ev::change, move |ev| {
+                value_setter.set(event_target_value(&ev));
+            }),
+        };
+
+        let view = Self::labeled_control(
+            &control.styles,
+            &control.id,
+            control.data.label.as_ref(),
+            input.into_view(),
+            validation_state,
+        );
+
+        self.common_component(&control.styles, "", view)
+    }
+
+    fn radio_buttons(
+        &self,
+        control: Rc<ControlRenderData<Self, RadioButtonsData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let buttons_view = control
+            .data
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, (display, value))| {
+                let option_id = format!("{}-{}", control.id, i);
+                let display = display.clone();
+                let value = value.clone();
+                let value_clone = value.clone();
+                let value_clone2 = value.clone();
+                view! {
+                    <div class="form-check">
+                        <input
+                            type="radio"
+                            id=&option_id
+                            name=&control.data.name
+                            value=&value
+                            class="form-check-input"
+                            class=("is-invalid", move || validation_state.get().is_err())
+                            prop:checked=move || { value_getter.get() == value_clone }
+                            on:input=move |ev| {
+                                let new_value = event_target_checked(&ev);
+                                if new_value {
+                                    value_setter.set(value_clone2.clone());
+                                }
+                            }
+                        />
+                        <label for=&option_id class="form-check-label">{display}</label>
+                    </div>
+                }
+            })
+            .collect_view();
+
+        let view = view! {
+            <div class="form-label" id=format!("{}-label", control.id)>
+                {control.data.label.as_ref()}
+            </div>
+            <div
+                role="radiogroup"
+                aria-labelledby=format!("{}-label", control.id)
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &control.id)
+            >
+                {buttons_view}
+            </div>
+            // the feedback is not a sibling of the inputs, so it needs to be shown manually
+            <div
+                class=move || match validation_state.get().is_err() {
+                    true => "invalid-feedback d-block",
+                    false => Self::feedback_class(validation_state),
+                }
+                id=error_id(&control.id)
+                aria-live="polite"
+            >
+                {move || validation_state.get().take_msg()}
+            </div>
+            {Self::help_text(&control.styles, &control.id)}
+        }
+        .into_view();
+
+        self.common_component(&control.styles, "", view)
+    }
+
+    fn select(
+        &self,
+        control: Rc<ControlRenderData<Self, SelectData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let control_clone = control.clone();
+        let options_view = move || {
+            control_clone
+                .data
+                .options
+                .get()
+                .iter()
+                .map(|(display, value)| {
+                    let display = display.clone();
+                    let value = value.clone();
+                    view! {
+                        <option value=value.clone() selected=move || { value_getter.get() == *value }>
+                            {display}
+                        </option>
+                    }
+                })
+                .collect_view()
+        };
+
+        let blank_option_view = control.data.blank_option.as_ref().map(|display| {
+            view! {
+                <option value="" selected=move || { value_getter.get().as_str() == "" }>
+                    {display}
+                </option>
+            }
+        });
+
+        let select = view! {
+            <select
+                id=&control.id
+                name=&control.data.name
+                class=Self::sized_class("form-select", &control.styles)
+                class=("is-invalid", move || validation_state.get().is_err())
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &control.id)
+                on:input=move |ev| {
+                    value_setter.set(event_target_value(&ev));
+                }
+            >
+                {blank_option_view}
+                {options_view}
+            </select>
+        }
+        .into_view();
+
+        let view = Self::labeled_control(
+            &control.styles,
+            &control.id,
+            control.data.label.as_ref(),
+            select,
+            validation_state,
+        );
+
+        self.common_component(&control.styles, "", view)
+    }
+
+    fn checkbox(
+        &self,
+        control: Rc<ControlRenderData<Self, CheckboxData>>,
+        value_getter: Signal<bool>,
+        value_setter: SignalSetter<bool>,
+    ) -> View {
+        let label = control
+            .data
+            .label
+            .clone()
+            .unwrap_or(control.data.name.clone());
+
+        let view = view! {
+            <div class="form-check">
+                <input
+                    type="checkbox"
+                    id=&control.id
+                    name=&control.data.name
+                    class="form-check-input"
+                    aria-required=control.required.then_some("true")
+                    prop:checked=value_getter
+                    on:input=move |ev| {
+                        let new_value = event_target_checked(&ev);
+                        value_setter.set(new_value);
+                    }
+                />
+                <label for=&control.id class="form-check-label">
+                    {label}
+                </label>
+            </div>
+            {Self::help_text(&control.styles, &control.id)}
+        }
+        .into_view();
+
+        self.common_component(&control.styles, "", view)
+    }
+
+    fn switch(
+        &self,
+        control: Rc<ControlRenderData<Self, SwitchData>>,
+        value_getter: Signal<bool>,
+        value_setter: SignalSetter<bool>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let label = control
+            .data
+            .label
+            .clone()
+            .unwrap_or(control.data.name.clone());
+        let data = control.clone();
+        let state_label = move || data.data.state_label(value_getter.get()).map(String::from);
+
+        let view = view! {
+            <div class="form-check form-switch">
+                <input
+                    type="checkbox"
+                    role="switch"
+                    id=&control.id
+                    name=&control.data.name
+                    class="form-check-input"
+                    class=("is-invalid", move || validation_state.get().is_err())
+                    disabled=control.data.disabled
+                    aria-invalid=move || validation_state.get().is_err().to_string()
+                    aria-required=control.required.then_some("true")
+                    aria-describedby=Self::described_by(&control.styles, &control.id)
+                    prop:checked=value_getter
+                    on:input=move |ev| {
+                        let new_value = event_target_checked(&ev);
+                        value_setter.set(new_value);
+                    }
+                />
+                <label for=&control.id class="form-check-label">
+                    {label}
+                </label>
+                <span class="ms-2 text-body-secondary">{state_label}</span>
+                {Self::feedback(&control.id, validation_state)}
+            </div>
+            {Self::help_text(&control.styles, &control.id)}
+        }
+        .into_view();
+
+        self.common_component(&control.styles, "", view)
+    }
+
+    fn stepper(
+        &self,
+        control: Rc<ControlRenderData<Self, StepperData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let input = view! {
+            <input
+                type="number"
+                id=&control.id
+                name=&control.data.name
+                step=control.data.step.clone()
+                min=control.data.min.clone()
+                max=control.data.max.clone()
+                class=Self::sized_class("form-control", &control.styles)
+                class=("is-invalid", move || validation_state.get().is_err())
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &control.id)
+                prop:value=move || value_getter.get()
+                on:input=move |ev| {
+                    value_setter.set(event_target_value(&ev));
+                }
+            />
+        }
+        .into_view();
+
+        let view = Self::labeled_control(
+            &control.styles,
+            &control.id,
+            control.data.label.as_ref(),
+            input,
+            validation_state,
+        );
+
+        self.common_component(&control.styles, "", view)
+    }
+
+    fn slider(
+        &self,
+        control: Rc<ControlRenderData<Self, SliderData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let view = view! {
+            <label for=&control.id class="form-label">
+                {control.data.label.as_ref()}
+            </label>
+            <input
+                type="range"
+                id=&control.id
+                name=&control.data.name
+                step=control.data.step.clone()
+                min=control.data.min.clone()
+                max=control.data.max.clone()
+                class="form-range"
+                class=("is-invalid", move || validation_state.get().is_err())
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &control.id)
+                prop:value=move || value_getter.get()
+                on:input=move |ev| {
+                    let value = event_target_value(&ev);
+                    value_setter.set(value);
+                }
+            />
+            {Self::feedback(&control.id, validation_state)}
+            {Self::help_text(&control.styles, &control.id)}
+        }
+        .into_view();
+
+        self.common_component(&control.styles, "", view)
+    }
+
+    fn date(
+        &self,
+        control: Rc<ControlRenderData<Self, DateData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let input = view! {
+            <input
+                type="date"
+                id=&control.id
+                name=&control.data.name
+                min=control.data.min.clone()
+                max=control.data.max.clone()
+                title=control.data.title.clone()
+                class=Self::sized_class("form-control", &control.styles)
+                class=("is-invalid", move || validation_state.get().is_err())
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &control.id)
+                prop:value=move || value_getter.get()
+                on:input=move |ev| {
+                    let value = event_target_value(&ev);
+                    value_setter.set(value);
+                }
+            />
+        }
+        .into_view();
+
+        let view = Self::labeled_control(
+            &control.styles,
+            &control.id,
+            control.data.label.as_ref(),
+            input,
+            validation_state,
+        );
+
+        self.common_component(&control.styles, "", view)
+    }
+}
@@ -1,10 +1,22 @@
-use super::FormStyle;
+use super::{error_id, help_id, Breakpoint, FormStyle};
 use crate::controls::{
-    button::ButtonData, checkbox::CheckboxData, date::DateData, heading::HeadingData,
-    hidden::HiddenData, output::OutputData, radio_buttons::RadioButtonsData, select::SelectData,
-    slider::SliderData, spacer::SpacerData, stepper::StepperData, submit::SubmitData,
-    text_area::TextAreaData, text_input::TextInputData, ControlRenderData, UpdateEvent,
-    ValidationState,
+    button::ButtonData,
+    checkbox::CheckboxData,
+    date::DateData,
+    heading::HeadingData,
+    hidden::HiddenData,
+    output::OutputData,
+    password::{PasswordData, PasswordStrength},
+    radio_buttons::RadioButtonsData,
+    select::SelectData,
+    slider::SliderData,
+    spacer::SpacerData,
+    stepper::StepperData,
+    submit::SubmitData,
+    switch::SwitchData,
+    text_area::TextAreaData,
+    text_input::TextInputData,
+    ControlRenderData, UpdateEvent, ValidationState,
 };
 use leptos::*;
 use std::rc::Rc;
@@ -20,72 +32,338 @@ pub enum FbStyleAttr {
     /// This sets the html title attribute, which shows the text when the
     /// user hovers their mouse over the control for a couple seconds.
     Tooltip(String),
+    /// Set the width of the control out of 12, from the given breakpoint
+    /// and up.
+    ///
+    /// Ex. `Width(12)` and `WidthAt(Breakpoint::Md, 6)` makes the control
+    /// full width on phones and half width on larger screens.
+    WidthAt(Breakpoint, u32),
+    /// Adds a css class to the element wrapping the control.
+    Class(String),
+    /// Sets the html id of the control's input element.
+    ///
+    /// Defaults to the unique id generated by the form. When setting this,
+    /// make sure it is unique on the page.
+    Id(String),
+    /// Adds help text underneath the control.
+    HelpText(String),
+    /// Overrides the theme's class for the control's input element.
+    InputClass(String),
+    /// Overrides the theme's class for the control's input element when
+    /// validation fails.
+    InputErrorClass(String),
+    /// Overrides the theme's class for the control's label.
+    LabelClass(String),
+    /// Overrides the theme's class for the control's label when validation
+    /// fails.
+    LabelErrorClass(String),
+    /// Overrides the theme's class for the control's error message.
+    ErrorMessageClass(String),
+    /// Overrides the theme's class for the control's warning message.
+    WarningMessageClass(String),
 }
 
-/// A complete useable example for defining a form style.
+/// The tailwind classes that the [`FbFormStyle`] uses to
+/// render controls.
 ///
-/// This can be used directly in by your form, or you can copy `grid_form.rs`
-/// into your project and make any neccesary change. You will also want to
-/// copy `grid_form.scss` from the git repo and put that in the `styles`
-/// directory for your leptos project to get all the styling.
-#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct FbFormStyle;
+/// [`FbTheme::DEFAULT`] gives the stock flowbite look. To rebrand the form,
+/// override the fields you want to change and keep the rest with
+/// `..FbTheme::DEFAULT`, which also works in a `const` or `static`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FbTheme {
+    /// The class of the element wrapping the whole form.
+    pub form_frame: &'static str,
+    /// The class of the element wrapping a group.
+    pub group: &'static str,
+    /// The class for `h1` headings.
+    pub heading_h1: &'static str,
+    /// The class for `h2` headings.
+    pub heading_h2: &'static str,
+    /// The class for `h3` headings.
+    pub heading_h3: &'static str,
+    /// The class for `h4` headings.
+    pub heading_h4: &'static str,
+    /// The class for the submit button.
+    pub submit: &'static str,
+    /// The class for buttons.
+    pub button: &'static str,
+    /// The class for inputs, selects, steppers, sliders and dates.
+    pub input: &'static str,
+    /// The class for inputs, selects, steppers, sliders and dates when
+    /// validation fails.
+    pub input_error: &'static str,
+    /// The class for text areas.
+    pub text_area: &'static str,
+    /// The class for text areas when validation fails.
+    pub text_area_error: &'static str,
+    /// The class for each radio button.
+    pub radio: &'static str,
+    /// The class for each radio button when validation fails.
+    pub radio_error: &'static str,
+    /// The class for the label next to each radio button.
+    pub radio_label: &'static str,
+    /// The class for checkboxes.
+    pub checkbox: &'static str,
+    /// The class for the label next to a checkbox.
+    pub checkbox_label: &'static str,
+    /// The class for the track of switches.
+    pub switch: &'static str,
+    /// The class for the track of switches when validation fails.
+    pub switch_error: &'static str,
+    /// The class for the on or off label next to a switch.
+    pub switch_label: &'static str,
+    /// The class for the button that shows and hides a password.
+    pub password_toggle: &'static str,
+    /// The class for the track of password strength meters.
+    pub strength_track: &'static str,
+    /// The classes for the bar of password strength meters, indexed by the
+    /// password's score.
+    pub strength_bar: [&'static str; 5],
+    /// The class for the text describing a password's strength.
+    pub strength_label: &'static str,
+    /// The class for control labels.
+    pub label: &'static str,
+    /// The class for control labels when validation fails.
+    pub label_error: &'static str,
+    /// The class for validation error messages.
+    pub error_message: &'static str,
+    /// The class for validation warning messages.
+    pub warning_message: &'static str,
+    /// The class for help text.
+    pub help_text: &'static str,
+    /// The class for the labels when reviewing the form.
+    pub review_label: &'static str,
+    /// The class for the values when reviewing the form.
+    pub review_value: &'static str,
+}
 
-impl FbFormStyle {
-    fn input_class() -> &'static str {
-        "bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+impl FbTheme {
+    /// The stock flowbite theme.
+    pub const DEFAULT: FbTheme = FbTheme {
+        form_frame: "grid grid-cols-12 gap-4",
+        group: "form_group form_grid",
+        heading_h1: "mb-4 text-4xl font-extrabold leading-none tracking-tight text-gray-900 md:text-5xl lg:text-6xl dark:text-white",
+        heading_h2: "text-4xl font-extrabold dark:text-white",
+        heading_h3: "text-3xl font-extrabold dark:text-white",
+        heading_h4: "text-2xl font-extrabold dark:text-white",
+        submit: "flex items-center bg-sky-500 hover:bg-sky-700 px-5 py-1 text-sm rounded-full font-semibold text-white",
+        button: "flex items-center bg-sky-500 hover:bg-sky-700 px-5 py-1 text-sm rounded-full font-semibold text-white",
+        input: "bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500",
+        input_error: "bg-red-50 border border-red-500 text-red-900 placeholder-red-700 text-sm rounded-lg focus:ring-red-500 dark:bg-gray-700 focus:border-red-500 block w-full p-2.5 dark:text-red-500 dark:placeholder-red-500 dark:border-red-500",
+        text_area: "block w-full p-4 text-gray-900 border border-gray-300 rounded-lg bg-gray-50 text-base focus:ring-blue-500 focus:border-blue-500 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500",
+        text_area_error: "block w-full p-4 bg-red-50 border border-red-500 text-red-900 placeholder-red-700 text-base rounded-lg  focus:ring-red-500 focus:border-red-500 dark:bg-gray-700 dark:border-red-600 dark:placeholder-red-500 dark:text-red-500 dark:focus:ring-red-500 dark:focus:border-red-500",
+        radio: "w-4 h-4 text-blue-600 bg-gray-100 border-gray-300 focus:ring-blue-500 dark:focus:ring-blue-600 dark:ring-offset-gray-800 focus:ring-2 dark:bg-gray-700 dark:border-gray-600",
+        radio_error: "w-4 h-4 text-red-900 bg-gray-100 border-red-500 focus:ring-red-500 dark:focus:ring-red-600 dark:ring-offset-red-800 focus:ring-2 dark:bg-red-700 dark:border-red-600",
+        radio_label: "ms-2 text-sm font-medium text-gray-900 dark:text-gray-300",
+        checkbox: "w-4 h-4 text-blue-600 bg-gray-100 border-gray-300 rounded focus:ring-blue-500 dark:focus:ring-blue-600 dark:ring-offset-gray-800 focus:ring-2 dark:bg-gray-700 dark:border-gray-600",
+        checkbox_label: "ms-2 text-sm font-medium text-gray-900 dark:text-gray-300",
+        switch: "relative w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-blue-300 dark:peer-focus:ring-blue-800 rounded-full peer dark:bg-gray-700 peer-checked:after:translate-x-full rtl:peer-checked:after:-translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:start-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:w-5 after:h-5 after:transition-all dark:border-gray-600 peer-checked:bg-blue-600 peer-disabled:opacity-50",
+        switch_error: "relative w-11 h-6 bg-red-100 ring-2 ring-red-500 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-red-300 dark:peer-focus:ring-red-800 rounded-full peer dark:bg-gray-700 peer-checked:after:translate-x-full rtl:peer-checked:after:-translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:start-[2px] after:bg-white after:border-red-300 after:border after:rounded-full after:w-5 after:h-5 after:transition-all dark:border-red-600 peer-checked:bg-red-600 peer-disabled:opacity-50",
+        switch_label: "ms-3 text-sm font-medium text-gray-900 dark:text-gray-300",
+        password_toggle: "absolute inset-y-0 end-0 flex items-center px-3 text-sm font-medium text-blue-700 hover:underline dark:text-blue-500",
+        strength_track: "w-full h-1.5 mt-2 bg-gray-200 rounded-full dark:bg-gray-700",
+        strength_bar: [
+            "h-1.5 rounded-full bg-red-600",
+            "h-1.5 rounded-full bg-orange-500",
+            "h-1.5 rounded-full bg-yellow-400",
+            "h-1.5 rounded-full bg-lime-500",
+            "h-1.5 rounded-full bg-green-600",
+        ],
+        strength_label: "mt-1 text-sm text-gray-500 dark:text-gray-400",
+        label: "block mb-2 text-sm font-medium text-gray-900 dark:text-white",
+        label_error: "block mb-2 text-sm font-medium text-red-700 dark:text-red-500",
+        error_message: "mt-2 text-sm text-red-600 dark:text-red-500",
+        warning_message: "mt-2 text-sm text-yellow-600 dark:text-yellow-500",
+        help_text: "mt-2 text-sm text-gray-500 dark:text-gray-400",
+        review_label: "mb-1 text-sm text-gray-500 dark:text-gray-400",
+        review_value: "text-base font-semibold text-gray-900 dark:text-white",
+    };
+}
+
+impl Default for FbTheme {
+    fn default() -> Self {
+        FbTheme::DEFAULT
+    }
+}
+
+/// The classes for a single control, after the per-control overrides have
+/// been applied to the theme.
+struct FbClasses {
+    input: String,
+    input_error: String,
+    label: String,
+    label_error: String,
+    error_message: String,
+    warning_message: String,
+}
+
+impl FbClasses {
+    /// Gets a derived signal for the input class based on the validation
+    /// state.
+    fn input(&self, validation_state: Signal<ValidationState>) -> impl Fn() -> String + 'static {
+        let (class, error_class) = (self.input.clone(), self.input_error.clone());
+        move || match validation_state.get().is_err() {
+            true => error_class.clone(),
+            false => class.clone(),
+        }
     }
 
-    fn input_class_error() -> &'static str {
-        "bg-red-50 border border-red-500 text-red-900 placeholder-red-700 text-sm rounded-lg focus:ring-red-500 dark:bg-gray-700 focus:border-red-500 block w-full p-2.5 dark:text-red-500 dark:placeholder-red-500 dark:border-red-500"
+    /// Gets a derived signal for the label class based on the validation
+    /// state.
+    fn label(&self, validation_state: Signal<ValidationState>) -> impl Fn() -> String + 'static {
+        let (class, error_class) = (self.label.clone(), self.label_error.clone());
+        move || match validation_state.get().is_err() {
+            true => error_class.clone(),
+            false => class.clone(),
+        }
     }
 
-    fn text_class() -> &'static str {
-        "block w-full p-4 text-gray-900 border border-gray-300 rounded-lg bg-gray-50 text-base focus:ring-blue-500 focus:border-blue-500 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
+    /// Gets a derived signal for the error message class. The message
+    /// element is always rendered so screen readers pick up changes to it,
+    /// so it is only given its classes while there is an error or warning
+    /// to show.
+    fn error_message(
+        &self,
+        validation_state: Signal<ValidationState>,
+    ) -> impl Fn() -> String + 'static {
+        let (class, warning_class) = (self.error_message.clone(), self.warning_message.clone());
+        move || {
+            let state = validation_state.get();
+            if state.is_err() {
+                class.clone()
+            } else if state.is_warning() {
+                warning_class.clone()
+            } else {
+                String::new()
+            }
+        }
     }
+}
 
-    fn text_class_error() -> &'static str {
-        "block w-full p-4 bg-red-50 border border-red-500 text-red-900 placeholder-red-700 text-base rounded-lg  focus:ring-red-500 focus:border-red-500 dark:bg-gray-700 dark:border-red-600 dark:placeholder-red-500 dark:text-red-500 dark:focus:ring-red-500 dark:focus:border-red-500"
+/// A complete useable example for defining a form style.
+///
+/// This can be used directly in by your form, or you can copy `grid_form.rs`
+/// into your project and make any neccesary change. You will also want to
+/// copy `grid_form.scss` from the git repo and put that in the `styles`
+/// directory for your leptos project to get all the styling.
+///
+/// The classes used for rendering come from the style's [`FbTheme`], and can
+/// be overridden per control with the [`FbStyleAttr`] class attributes.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FbFormStyle {
+    /// The classes used to render the controls.
+    pub theme: FbTheme,
+}
+
+impl Default for FbFormStyle {
+    fn default() -> Self {
+        FbFormStyle::new()
     }
+}
 
-    fn radio_class() -> &'static str {
-        "w-4 h-4 text-blue-600 bg-gray-100 border-gray-300 focus:ring-blue-500 dark:focus:ring-blue-600 dark:ring-offset-gray-800 focus:ring-2 dark:bg-gray-700 dark:border-gray-600"
+impl FbFormStyle {
+    /// Creates a new [`FbFormStyle`](Self) with the default theme.
+    pub const fn new() -> Self {
+        FbFormStyle::with_theme(FbTheme::DEFAULT)
     }
 
-    fn radio_class_error() -> &'static str {
-        "w-4 h-4 text-red-900 bg-gray-100 border-red-500 focus:ring-red-500 dark:focus:ring-red-600 dark:ring-offset-red-800 focus:ring-2 dark:bg-red-700 dark:border-red-600"
+    /// Creates a new [`FbFormStyle`](Self) with the given theme.
+    pub const fn with_theme(theme: FbTheme) -> Self {
+        FbFormStyle { theme }
     }
 
-    fn label_class() -> &'static str {
-        "block mb-2 text-sm font-medium text-gray-900 dark:text-white"
+    /// Gets the classes for a control, applying any overrides from the
+    /// styling attributes.
+    ///
+    /// `input` and `input_error` are the theme's classes for the control's
+    /// input element.
+    fn classes(&self, styles: &[FbStyleAttr], input: &str, input_error: &str) -> FbClasses {
+        let mut classes = FbClasses {
+            input: input.to_string(),
+            input_error: input_error.to_string(),
+            label: self.theme.label.to_string(),
+            label_error: self.theme.label_error.to_string(),
+            error_message: self.theme.error_message.to_string(),
+            warning_message: self.theme.warning_message.to_string(),
+        };
+        for style in styles.iter() {
+            match style {
+                FbStyleAttr::InputClass(c) => classes.input = c.clone(),
+                FbStyleAttr::InputErrorClass(c) => classes.input_error = c.clone(),
+                FbStyleAttr::LabelClass(c) => classes.label = c.clone(),
+                FbStyleAttr::LabelErrorClass(c) => classes.label_error = c.clone(),
+                FbStyleAttr::ErrorMessageClass(c) => classes.error_message = c.clone(),
+                FbStyleAttr::WarningMessageClass(c) => classes.warning_message = c.clone(),
+                _ => {}
+            }
+        }
+        classes
     }
 
-    fn label_class_error() -> &'static str {
-        "block mb-2 text-sm font-medium text-red-700 dark:text-red-500"
+    /// The class that applies the `--fb-span-*` width variable at the given
+    /// breakpoint.
+    ///
+    /// These are full class names so that tailwind can find them, and they
+    /// are marked important to take precedence over the inline base width.
+    fn breakpoint_class(breakpoint: Breakpoint) -> &'static str {
+        match breakpoint {
+            Breakpoint::Sm => "sm:![grid-column:span_var(--fb-span-sm)]",
+            Breakpoint::Md => "md:![grid-column:span_var(--fb-span-md)]",
+            Breakpoint::Lg => "lg:![grid-column:span_var(--fb-span-lg)]",
+            Breakpoint::Xl => "xl:![grid-column:span_var(--fb-span-xl)]",
+            Breakpoint::Xxl => "2xl:![grid-column:span_var(--fb-span-xxl)]",
+        }
     }
 
-    fn class_error_message() -> &'static str {
-        "mt-2 text-sm text-red-600 dark:text-red-500"
+    /// Gets the ids of the elements describing a control: the validation
+    /// message and the help text, if there is any.
+    fn described_by(styles: &[FbStyleAttr], id: &str) -> String {
+        let mut described_by = error_id(id);
+        if styles.iter().any(|s| matches!(s, FbStyleAttr::HelpText(_))) {
+            described_by.push(' ');
+            described_by.push_str(&help_id(id));
+        }
+        described_by
     }
 
     fn common_component(
         &self,
+        id: Option<&str>,
         styles: &[<FbFormStyle as FormStyle>::StylingAttributes],
         parent_class: &'static str,
         inner: View,
     ) -> View {
         let mut width = 12;
         let mut tooltip = None;
-        for style in styles.iter() {
-            match style {
+        let mut help_text = None;
+        let mut class = String::from(parent_class);
+        let mut style = String::new();
+        for attr in styles.iter() {
+            match attr {
                 FbStyleAttr::Width(w) => width = *w,
+                FbStyleAttr::WidthAt(bp, w) => {
+                    class.push(' ');
+                    class.push_str(Self::breakpoint_class(*bp));
+                    style.push_str(&format!(" --fb-span-{}: {};", bp.bootstrap_infix(), w));
+                }
                 FbStyleAttr::Tooltip(t) => tooltip = Some(t),
+                FbStyleAttr::Class(c) => {
+                    class.push(' ');
+                    class.push_str(c);
+                }
+                FbStyleAttr::HelpText(t) => help_text = Some(t.clone()),
+                _ => {}
             }
         }
+        let style = format!("grid-column: span {};{}", width, style);
+        let help_id = id.map(help_id);
+        let help_text =
+            help_text.map(|t| view! { <p class=self.theme.help_text id=help_id>{t}</p> });
 
         view! {
-            <div class=parent_class style:grid-column=format!("span {}", width) title=tooltip>
+            <div class=class style=style title=tooltip>
                 {inner}
+                {help_text}
             </div>
         }
         .into_view()
@@ -94,23 +372,36 @@ impl FbFormStyle {
 impl FormStyle for FbFormStyle {
     type StylingAttributes = FbStyleAttr;
 
+    /// Uses the [`FbStyleAttr::Id`] if there is one.
+    fn control_id(&self, styles: &[FbStyleAttr], id: &str) -> String {
+        styles
+            .iter()
+            .rev()
+            .find_map(|style| match style {
+                FbStyleAttr::Id(id) => Some(id.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| id.to_string())
+    }
+
     fn form_frame(&self, form: ControlRenderData<Self, View>) -> View {
-        view! { <div class="grid grid-cols-12 gap-4">{form.data}</div> }.into_view()
+        view! { <div class=self.theme.form_frame>{form.data}</div> }.into_view()
     }
 
     /// A common function that wraps the given view in the styles
     fn custom_component(&self, styles: &[Self::StylingAttributes], inner: View) -> View {
-        self.common_component(styles, "custom_component_parent", inner)
+        self.common_component(None, styles, "custom_component_parent", inner)
     }
 
     fn group(&self, group: Rc<ControlRenderData<Self, View>>) -> View {
-        let view = view! { <div class="form_group form_grid">{&group.data}</div> }.into_view();
+        let view = view! { <div class=self.theme.group>{&group.data}</div> }.into_view();
 
-        self.common_component(&group.styles, "group_parent", view)
+        self.common_component(None, &group.styles, "group_parent", view)
     }
 
     fn spacer(&self, control: Rc<ControlRenderData<Self, SpacerData>>) -> View {
         self.common_component(
+            None,
             &control.styles,
             "spacer_parent",
             view! { <div style:height=control.data.height.as_ref()></div> }.into_view(),
@@ -127,13 +418,13 @@ impl FormStyle for FbFormStyle {
         let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
 
         let view = match control.data.level {
-            H1 => view! { <h1 class="mb-4 text-4xl font-extrabold leading-none tracking-tight text-gray-900 md:text-5xl lg:text-6xl dark:text-white"> {title} </h1> }.into_view(),
-            H2 => view! { <h2 class="text-4xl font-extrabold dark:text-white"> {title} </h2> }.into_view(),
-            H3 => view! { <h3 class="text-3xl font-extrabold dark:text-white"> {title} </h3> }.into_view(),
-            H4 => view! { <h4 class="text-2xl font-extrabold dark:text-white"> {title} </h4> }.into_view(),
+            H1 => view! { <h1 class=self.theme.heading_h1> {title} </h1> }.into_view(),
+            H2 => view! { <h2 class=self.theme.heading_h2> {title} </h2> }.into_view(),
+            H3 => view! { <h3 class=self.theme.heading_h3> {title} </h3> }.into_view(),
+            H4 => view! { <h4 class=self.theme.heading_h4> {title} </h4> }.into_view(),
         };
 
-        self.common_component(&control.styles, "flew flex-row", view)
+        self.common_component(None, &control.styles, "flew flex-row", view)
     }
 
     fn submit(
@@ -142,11 +433,13 @@ impl FormStyle for FbFormStyle {
         value_getter: Option<Signal<String>>,
     ) -> View {
         let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
+        let classes = self.classes(&control.styles, self.theme.submit, self.theme.submit);
 
         self.common_component(
+            None,
             &control.styles,
             "",
-            view! { <input type="submit" value=title class="flex items-center bg-sky-500 hover:bg-sky-700 px-5 py-1 text-sm rounded-full font-semibold text-white" /> }.into_view(),
+            view! { <input type="submit" value=title class=classes.input /> }.into_view(),
         )
     }
 
@@ -163,15 +456,16 @@ impl FormStyle for FbFormStyle {
         };
 
         let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
+        let classes = self.classes(&control.styles, self.theme.button, self.theme.button);
 
         let view = view! {
-            <button type="button" class="flex items-center bg-sky-500 hover:bg-sky-700 px-5 py-1 text-sm rounded-full font-semibold text-white" on:click=on_click>
+            <button type="button" class=classes.input on:click=on_click>
                 {title}
             </button>
         }
         .into_view();
 
-        self.common_component(&control.styles, "", view)
+        self.common_component(None, &control.styles, "", view)
     }
 
     fn output(
@@ -180,7 +474,23 @@ impl FormStyle for FbFormStyle {
         value_getter: Option<Signal<String>>,
     ) -> View {
         let view = view! { <span>{move || value_getter.map(|g| g.get())}</span> }.into_view();
-        self.common_component(&control.styles, "output_parent", view)
+        self.common_component(None, &control.styles, "output_parent", view)
+    }
+
+    fn review_item(
+        &self,
+        styles: &[Self::StylingAttributes],
+        label: String,
+        value: Signal<String>,
+    ) -> View {
+        let view = view! {
+            <dl>
+                <dt class=self.theme.review_label>{label}</dt>
+                <dd class=self.theme.review_value>{value}</dd>
+            </dl>
+        }
+        .into_view();
+        self.common_component(None, styles, "review_item_parent", view)
     }
 
     fn hidden(
@@ -206,23 +516,19 @@ impl FormStyle for FbFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
-        let input_class = move || {
-            if validation_state.get().is_err() {
-                Self::input_class_error()
-            } else {
-                Self::input_class()
-            }
-        };
+        let id = self.control_id(&control.styles, &control.id);
+        let classes = self.classes(&control.styles, self.theme.input, self.theme.input_error);
 
         let input = view! {
             <input
                 type=control.data.input_type
-                id=&control.data.name
+                id=&id
                 name=&control.data.name
                 placeholder=control.data.placeholder.as_ref()
-                class=input_class
-                // class=input_class
-                // class=("form_input_invalid", move || validation_state.get().is_err())
+                class=classes.input(validation_state)
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &id)
                 prop:value=move || value_getter.get()
             />
         };
@@ -239,28 +545,113 @@ impl FormStyle for FbFormStyle {
             }),
         };
 
-        let label_class = move || {
-            if validation_state.get().is_err() {
-                Self::label_class_error()
-            } else {
-                Self::label_class()
+        let view = view! {
+            <label for=&id class=classes.label(validation_state)>
+                {control.data.label.as_ref()}
+            </label>
+            {input}
+            <p
+                id=error_id(&id)
+                aria-live="polite"
+                class=classes.error_message(validation_state)
+            >
+                {move || validation_state.get().take_msg()}
+            </p>
+        }
+        .into_view();
+
+        self.common_component(Some(&id), &control.styles, "", view)
+    }
+
+    fn password(
+        &self,
+        control: Rc<ControlRenderData<Self, PasswordData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
+        let classes = self.classes(&control.styles, self.theme.input, self.theme.input_error);
+        let shown = create_rw_signal(false);
+
+        let data = control.clone();
+        let toggle = control.data.reveal_labels.is_some().then(|| {
+            view! {
+                <button
+                    type="button"
+                    class=self.theme.password_toggle
+                    aria-controls=&id
+                    aria-pressed=move || shown.get().to_string()
+                    on:click=move |_| shown.update(|shown| *shown = !*shown)
+                >
+                    {move || data.data.reveal_label(shown.get()).map(String::from)}
+                </button>
             }
-        };
+        });
 
-        let class_error_message = Self::class_error_message();
+        let data = control.clone();
+        let strength = Signal::derive(move || data.data.strength(&value_getter.get()));
+        let meter = control.data.strength.is_some().then(|| {
+            let bars = self.theme.strength_bar;
+            let bar_class = move || {
+                let score = strength.get().map(|s| s.score).unwrap_or_default();
+                bars[score as usize]
+            };
+            view! {
+                <div
+                    class=self.theme.strength_track
+                    role="meter"
+                    aria-valuemin="0"
+                    aria-valuemax=PasswordStrength::MAX_SCORE
+                    aria-valuenow=move || strength.get().map(|s| s.score).unwrap_or_default()
+                >
+                    <div
+                        class=bar_class
+                        style:width=move || {
+                            format!("{}%", strength.get().map(|s| s.percent()).unwrap_or_default())
+                        }
+                    ></div>
+                </div>
+                <p class=self.theme.strength_label aria-live="polite">
+                    {move || strength.get().map(|s| s.label)}
+                </p>
+            }
+        });
 
         let view = view! {
-            <label for=&control.data.name class=label_class>
+            <label for=&id class=classes.label(validation_state)>
                 {control.data.label.as_ref()}
             </label>
-            {input}
-            <Show when=move || validation_state.get().is_err()>
-                <p class=class_error_message>{move || validation_state.get().take_msg()}</p>
-            </Show>
+            <div class="relative">
+                <input
+                    type=move || if shown.get() { "text" } else { "password" }
+                    id=&id
+                    name=&control.data.name
+                    placeholder=control.data.placeholder.as_ref()
+                    autocomplete=control.data.autocomplete
+                    class=classes.input(validation_state)
+                    aria-invalid=move || validation_state.get().is_err().to_string()
+                    aria-required=control.required.then_some("true")
+                    aria-describedby=Self::described_by(&control.styles, &id)
+                    prop:value=move || value_getter.get()
+                    on:input=move |ev| {
+                        value_setter.set(event_target_value(&ev));
+                    }
+                />
+                {toggle}
+            </div>
+            {meter}
+            <p
+                id=error_id(&id)
+                aria-live="polite"
+                class=classes.error_message(validation_state)
+            >
+                {move || validation_state.get().take_msg()}
+            </p>
         }
         .into_view();
 
-        self.common_component(&control.styles, "", view)
+        self.common_component(Some(&id), &control.styles, "", view)
     }
 
     fn text_area(
@@ -270,22 +661,24 @@ impl FormStyle for FbFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
-        let input_class = move || {
-            if validation_state.get().is_err() {
-                Self::text_class_error()
-            } else {
-                Self::text_class()
-            }
-        };
+        let id = self.control_id(&control.styles, &control.id);
+        let classes = self.classes(
+            &control.styles,
+            self.theme.text_area,
+            self.theme.text_area_error,
+        );
 
         let input = view! {
             <textarea
-                id=&control.data.name
+                id=&id
                 name=&control.data.name
                 placeholder=control.data.placeholder.as_ref()
                 prop:value=move || value_getter.get()
                 style="resize: vertical;"
-                class=input_class
+                class=classes.input(validation_state)
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &id)
             ></textarea>
         };
 
@@ -301,28 +694,22 @@ impl FormStyle for FbFormStyle {
             }),
         };
 
-        let label_class = move || {
-            if validation_state.get().is_err() {
-                Self::label_class_error()
-            } else {
-                Self::label_class()
-            }
-        };
-
-        let class_error_message = Self::class_error_message();
-
         let view = view! {
-            <label for=&control.data.name class=label_class>
+            <label for=&id class=classes.label(validation_state)>
                 {control.data.label.as_ref()}
             </label>
             {input}
-            <Show when=move || validation_state.get().is_err()>
-                <p class=class_error_message>{move || validation_state.get().take_msg()}</p>
-            </Show>
+            <p
+                id=error_id(&id)
+                aria-live="polite"
+                class=classes.error_message(validation_state)
+            >
+                {move || validation_state.get().take_msg()}
+            </p>
         }
         .into_view();
 
-        self.common_component(&control.styles, "", view)
+        self.common_component(Some(&id), &control.styles, "", view)
     }
 
     fn radio_buttons(
@@ -332,18 +719,15 @@ impl FormStyle for FbFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
-        let input_class = move || {
-            if validation_state.get().is_err() {
-                Self::radio_class_error()
-            } else {
-                Self::radio_class()
-            }
-        };
+        let id = self.control_id(&control.styles, &control.id);
+        let classes = self.classes(&control.styles, self.theme.radio, self.theme.radio_error);
         let buttons_view = control
             .data
             .options
             .iter()
-            .map(|(display, value)| {
+            .enumerate()
+            .map(|(i, (display, value))| {
+                let option_id = format!("{}-{}", id, i);
                 let display = display.clone();
                 let value = value.clone();
                 let value_clone = value.clone();
@@ -352,10 +736,10 @@ impl FormStyle for FbFormStyle {
                     <div class="flex items-center mb-4">
                         <input
                             type="radio"
-                            id=&value
+                            id=&option_id
                             name=&control.data.name
                             value=&value
-                            class=input_class
+                            class=classes.input(validation_state)
                             prop:checked=move || { value_getter.get() == value_clone }
                             on:input=move |ev| {
                                 let new_value = event_target_checked(&ev);
@@ -365,39 +749,38 @@ impl FormStyle for FbFormStyle {
                             }
                         />
 
-                        <label for=&value class="ms-2 text-sm font-medium text-gray-900 dark:text-gray-300">{display}</label>
+                        <label for=&option_id class=self.theme.radio_label>{display}</label>
                     </div>
                 }
             })
             .collect_view();
 
-        let label_class = move || {
-            if validation_state.get().is_err() {
-                Self::label_class_error()
-            } else {
-                Self::label_class()
-            }
-        };
-
-        let class_error_message = Self::class_error_message();
-
         let view = view! {
-            <label for=&control.data.name class=label_class>
+            <label id=format!("{}-label", id) class=classes.label(validation_state)>
                 {control.data.label.as_ref()}
             </label>
             <div
+                role="radiogroup"
+                aria-labelledby=format!("{}-label", id)
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &id)
                 class="flex flex-col"
                 class:form_input_invalid=move || validation_state.get().is_err()
             >
                 {buttons_view}
             </div>
-            <Show when=move || validation_state.get().is_err()>
-                <p class=class_error_message>{move || validation_state.get().take_msg()}</p>
-            </Show>
+            <p
+                id=error_id(&id)
+                aria-live="polite"
+                class=classes.error_message(validation_state)
+            >
+                {move || validation_state.get().take_msg()}
+            </p>
         }
         .into_view();
 
-        self.common_component(&control.styles, "", view)
+        self.common_component(Some(&id), &control.styles, "", view)
     }
 
     fn select(
@@ -407,6 +790,7 @@ impl FormStyle for FbFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
         let control_clone = control.clone();
         let options_view = move || {
             control_clone
@@ -434,30 +818,19 @@ impl FormStyle for FbFormStyle {
             }
         });
 
-        let class_error_message = Self::class_error_message();
-        let label_class = move || {
-            if validation_state.get().is_err() {
-                Self::label_class_error()
-            } else {
-                Self::label_class()
-            }
-        };
-        let input_class = move || {
-            if validation_state.get().is_err() {
-                Self::input_class_error()
-            } else {
-                Self::input_class()
-            }
-        };
+        let classes = self.classes(&control.styles, self.theme.input, self.theme.input_error);
 
         let view = view! {
-            <label for=&control.data.name class=label_class>
+            <label for=&id class=classes.label(validation_state)>
                 {control.data.label.as_ref()}
             </label>
             <select
-                id=&control.data.name
+                id=&id
                 name=&control.data.name
-                class=input_class
+                class=classes.input(validation_state)
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &id)
                 on:input=move |ev| {
                     value_setter.set(event_target_value(&ev));
                 }
@@ -465,13 +838,17 @@ impl FormStyle for FbFormStyle {
                 {blank_option_view}
                 {options_view}
             </select>
-            <Show when=move || validation_state.get().is_err()>
-                <p class=class_error_message>{move || validation_state.get().take_msg()}</p>
-            </Show>
+            <p
+                id=error_id(&id)
+                aria-live="polite"
+                class=classes.error_message(validation_state)
+            >
+                {move || validation_state.get().take_msg()}
+            </p>
         }
         .into_view();
 
-        self.common_component(&control.styles, "", view)
+        self.common_component(Some(&id), &control.styles, "", view)
     }
 
     fn checkbox(
@@ -480,20 +857,23 @@ impl FormStyle for FbFormStyle {
         value_getter: Signal<bool>,
         value_setter: SignalSetter<bool>,
     ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
         let label = control
             .data
             .label
             .clone()
             .unwrap_or(control.data.name.clone());
+        let classes = self.classes(&control.styles, self.theme.checkbox, self.theme.checkbox);
 
         let view = view! {
             <div class="flex items-center mb-4">
                 <input
                     type="checkbox"
-                    id=&control.data.name
+                    id=&id
                     name=&control.data.name
                     style="margin: auto 0;"
-                    class="w-4 h-4 text-blue-600 bg-gray-100 border-gray-300 rounded focus:ring-blue-500 dark:focus:ring-blue-600 dark:ring-offset-gray-800 focus:ring-2 dark:bg-gray-700 dark:border-gray-600"
+                    class=classes.input
+                    aria-required=control.required.then_some("true")
                     prop:checked=value_getter
                     on:input=move |ev| {
                         let new_value = event_target_checked(&ev);
@@ -501,8 +881,8 @@ impl FormStyle for FbFormStyle {
                     }
                 />
                 <label
-                    for=&control.data.name
-                    class="ms-2 text-sm font-medium text-gray-900 dark:text-gray-300"
+                    for=&id
+                    class=self.theme.checkbox_label
                     // class=("form_checkbox_checked", move || value_getter.get())
                     // class=("form_checkbox_unchecked", move || !value_getter.get())
                 >
@@ -512,7 +892,65 @@ impl FormStyle for FbFormStyle {
         }
         .into_view();
 
-        self.common_component(&control.styles, "", view)
+        self.common_component(Some(&id), &control.styles, "", view)
+    }
+
+    fn switch(
+        &self,
+        control: Rc<ControlRenderData<Self, SwitchData>>,
+        value_getter: Signal<bool>,
+        value_setter: SignalSetter<bool>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
+        let label = control
+            .data
+            .label
+            .clone()
+            .unwrap_or(control.data.name.clone());
+        let classes = self.classes(&control.styles, self.theme.switch, self.theme.switch_error);
+        let toggle_class = match control.data.disabled {
+            true => "inline-flex items-center cursor-not-allowed",
+            false => "inline-flex items-center cursor-pointer",
+        };
+        let data = control.clone();
+        let state_label = move || data.data.state_label(value_getter.get()).map(String::from);
+
+        let view = view! {
+            <label for=&id class=classes.label(validation_state)>
+                {label}
+            </label>
+            <label class=toggle_class>
+                <input
+                    type="checkbox"
+                    role="switch"
+                    id=&id
+                    name=&control.data.name
+                    class="sr-only peer"
+                    disabled=control.data.disabled
+                    aria-invalid=move || validation_state.get().is_err().to_string()
+                    aria-required=control.required.then_some("true")
+                    aria-describedby=Self::described_by(&control.styles, &id)
+                    prop:checked=value_getter
+                    on:input=move |ev| {
+                        let new_value = event_target_checked(&ev);
+                        value_setter.set(new_value);
+                    }
+                />
+                <div class=classes.input(validation_state)></div>
+                <span class=self.theme.switch_label>{state_label}</span>
+            </label>
+            <p
+                id=error_id(&id)
+                aria-live="polite"
+                class=classes.error_message(validation_state)
+            >
+                {move || validation_state.get().take_msg()}
+            </p>
+        }
+        .into_view();
+
+        self.common_component(Some(&id), &control.styles, "", view)
     }
 
     fn stepper(
@@ -522,47 +960,40 @@ impl FormStyle for FbFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
-        let label_class = move || {
-            if validation_state.get().is_err() {
-                Self::label_class_error()
-            } else {
-                Self::label_class()
-            }
-        };
-
-        let class_error_message = Self::class_error_message();
-        let input_class = move || {
-            if validation_state.get().is_err() {
-                Self::input_class_error()
-            } else {
-                Self::input_class()
-            }
-        };
+        let id = self.control_id(&control.styles, &control.id);
+        let classes = self.classes(&control.styles, self.theme.input, self.theme.input_error);
 
         let view = view! {
-            <label for=&control.data.name class=label_class>
+            <label for=&id class=classes.label(validation_state)>
                 {control.data.label.as_ref()}
             </label>
             <input
                 type="number"
-                id=&control.data.name
+                id=&id
                 name=&control.data.name
                 step=control.data.step.clone()
                 min=control.data.min.clone()
                 max=control.data.max.clone()
-                class=input_class
+                class=classes.input(validation_state)
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &id)
                 prop:value=move || value_getter.get()
                 on:input=move |ev| {
                     value_setter.set(event_target_value(&ev));
                 }
             />
-            <Show when=move || validation_state.get().is_err()>
-                <p class=class_error_message>{move || validation_state.get().take_msg()}</p>
-            </Show>
+            <p
+                id=error_id(&id)
+                aria-live="polite"
+                class=classes.error_message(validation_state)
+            >
+                {move || validation_state.get().take_msg()}
+            </p>
         }
         .into_view();
 
-        self.common_component(&control.styles, "", view)
+        self.common_component(Some(&id), &control.styles, "", view)
     }
 
     fn slider(
@@ -572,36 +1003,25 @@ impl FormStyle for FbFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
-        let label_class = move || {
-            if validation_state.get().is_err() {
-                Self::label_class_error()
-            } else {
-                Self::label_class()
-            }
-        };
-
-        let class_error_message = Self::class_error_message();
-        let input_class = move || {
-            if validation_state.get().is_err() {
-                Self::input_class_error()
-            } else {
-                Self::input_class()
-            }
-        };
+        let id = self.control_id(&control.styles, &control.id);
+        let classes = self.classes(&control.styles, self.theme.input, self.theme.input_error);
         // let min_label = move || match control.data.min.clone() {} format!("Min ({})", );
 
         let view = view! {
             <div class="relative mb-6">
-                <label for=&control.data.name class=label_class>
+                <label for=&id class=classes.label(validation_state)>
                     {control.data.label.as_ref()}
                 </label>
                 <input
                     type="range"
-                    id=&control.data.name
+                    id=&id
                     name=&control.data.name
                     min=control.data.min.clone()
                     max=control.data.max.clone()
-                    class=input_class
+                    class=classes.input(validation_state)
+                    aria-invalid=move || validation_state.get().is_err().to_string()
+                    aria-required=control.required.then_some("true")
+                    aria-describedby=Self::described_by(&control.styles, &id)
                     prop:value=move || value_getter.get()
                     on:input=move |ev| {
                         let value = event_target_value(&ev);
@@ -610,14 +1030,18 @@ impl FormStyle for FbFormStyle {
                 />
                 // <span class="text-sm text-gray-500 dark:text-gray-400 absolute start-0 -bottom-6">{min_label()}</span>
                 // <p class="text-sm text-gray-500 dark:text-gray-400 absolute end-0 -bottom-6">Max ({move || control.data.max.clone()})</p>
-                <Show when=move || validation_state.get().is_err()>
-                    <p class=class_error_message>{move || validation_state.get().take_msg()}</p>
-                </Show>
+                <p
+                    id=error_id(&id)
+                    aria-live="polite"
+                    class=classes.error_message(validation_state)
+                >
+                    {move || validation_state.get().take_msg()}
+                </p>
             </div>
         }
         .into_view();
 
-        self.common_component(&control.styles, "", view)
+        self.common_component(Some(&id), &control.styles, "", view)
     }
 
     fn date(
@@ -627,27 +1051,12 @@ impl FormStyle for FbFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
-        let label_class = move || {
-            if validation_state.get().is_err() {
-                Self::label_class_error()
-            } else {
-                Self::label_class()
-            }
-        };
-
-        //"bg-gray-50 border border-gray-300 text-gray-900 text-sm rounded-lg focus:ring-blue-500 focus:border-blue-500 block w-full ps-10 p-2.5 dark:bg-gray-700 dark:border-gray-600 dark:placeholder-gray-400 dark:text-white dark:focus:ring-blue-500 dark:focus:border-blue-500"
-        let class_error_message = Self::class_error_message();
-        let input_class = move || {
-            if validation_state.get().is_err() {
-                Self::input_class_error()
-            } else {
-                Self::input_class()
-            }
-        };
+        let id = self.control_id(&control.styles, &control.id);
+        let classes = self.classes(&control.styles, self.theme.input, self.theme.input_error);
         // let min_label = move || match control.data.min.clone() {} format!("Min ({})", );
 
         let view = view! {
-            <label for=&control.data.name class=label_class>
+            <label for=&id class=classes.label(validation_state)>
             {control.data.label.as_ref()}
             </label>
             <div class="relative mb-6">
@@ -658,14 +1067,17 @@ impl FormStyle for FbFormStyle {
                 // </div>
                 <input
                     type="text"
-                    id=&control.data.name
+                    id=&id
                     name=&control.data.name
                     min=control.data.min.clone()
                     max=control.data.max.clone()
                     datepicker=""
                     // datepicker-title=&control.data.title
                     placeholder="Select date"
-                    class=input_class
+                    class=classes.input(validation_state)
+                    aria-invalid=move || validation_state.get().is_err().to_string()
+                    aria-required=control.required.then_some("true")
+                    aria-describedby=Self::described_by(&control.styles, &id)
                     prop:value=move || value_getter.get()
                     on:input=move |ev| {
                         let value = event_target_value(&ev);
@@ -674,13 +1086,17 @@ impl FormStyle for FbFormStyle {
             />
         // <span class="text-sm text-gray-500 dark:text-gray-400 absolute start-0 -bottom-6">{min_label()}</span>
                 // <p class="text-sm text-gray-500 dark:text-gray-400 absolute end-0 -bottom-6">Max ({move || control.data.max.clone()})</p>
-                <Show when=move || validation_state.get().is_err()>
-                    <p class=class_error_message>{move || validation_state.get().take_msg()}</p>
-                </Show>
+                <p
+                    id=error_id(&id)
+                    aria-live="polite"
+                    class=classes.error_message(validation_state)
+                >
+                    {move || validation_state.get().take_msg()}
+                </p>
             </div>
         }
         .into_view();
 
-        self.common_component(&control.styles, "", view)
+        self.common_component(Some(&id), &control.styles, "", view)
     }
 }
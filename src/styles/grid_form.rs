@@ -1,10 +1,22 @@
-use super::FormStyle;
+use super::{error_id, help_id, Breakpoint, FormStyle};
 use crate::controls::{
-    button::ButtonData, checkbox::CheckboxData, date::DateData, heading::HeadingData,
-    hidden::HiddenData, output::OutputData, radio_buttons::RadioButtonsData, select::SelectData,
-    slider::SliderData, spacer::SpacerData, stepper::StepperData, submit::SubmitData,
-    text_area::TextAreaData, text_input::TextInputData, ControlRenderData, UpdateEvent,
-    ValidationState,
+    button::ButtonData,
+    checkbox::CheckboxData,
+    date::DateData,
+    heading::HeadingData,
+    hidden::HiddenData,
+    output::OutputData,
+    password::{PasswordData, PasswordStrength},
+    radio_buttons::RadioButtonsData,
+    select::SelectData,
+    slider::SliderData,
+    spacer::SpacerData,
+    stepper::StepperData,
+    submit::SubmitData,
+    switch::SwitchData,
+    text_area::TextAreaData,
+    text_input::TextInputData,
+    ControlRenderData, UpdateEvent, ValidationState,
 };
 use leptos::*;
 use std::rc::Rc;
@@ -16,10 +28,25 @@ pub enum GFStyleAttr {
     /// Set the width of the control out of 12.
     /// Defaults to 12/12 (full width).
     Width(u32),
+    /// Set the width of the control out of 12, from the given breakpoint
+    /// and up.
+    ///
+    /// Ex. `Width(12)` and `WidthAt(Breakpoint::Md, 6)` makes the control
+    /// full width on phones and half width on larger screens.
+    WidthAt(Breakpoint, u32),
     /// Adds a tooltip to the control.
     /// This sets the html title attribute, which shows the text when the
     /// user hovers their mouse over the control for a couple seconds.
     Tooltip(String),
+    /// Adds a css class to the element wrapping the control.
+    Class(String),
+    /// Sets the html id of the control's input element.
+    ///
+    /// Defaults to the unique id generated by the form. When setting this,
+    /// make sure it is unique on the page.
+    Id(String),
+    /// Adds help text underneath the control.
+    HelpText(String),
 }
 
 /// A complete useable example for defining a form style.
@@ -32,24 +59,66 @@ pub enum GFStyleAttr {
 pub struct GridFormStyle;
 
 impl GridFormStyle {
+    /// The class that applies the `--gf-span-*` width variable at the given
+    /// breakpoint. These are defined in `grid_form.scss`.
+    fn breakpoint_class(breakpoint: Breakpoint) -> &'static str {
+        match breakpoint {
+            Breakpoint::Sm => "gf_span_sm",
+            Breakpoint::Md => "gf_span_md",
+            Breakpoint::Lg => "gf_span_lg",
+            Breakpoint::Xl => "gf_span_xl",
+            Breakpoint::Xxl => "gf_span_xxl",
+        }
+    }
+
+    /// Gets the ids of the elements describing a control: the validation
+    /// message and the help text, if there is any.
+    fn described_by(styles: &[GFStyleAttr], id: &str) -> String {
+        let mut described_by = error_id(id);
+        if styles.iter().any(|s| matches!(s, GFStyleAttr::HelpText(_))) {
+            described_by.push(' ');
+            described_by.push_str(&help_id(id));
+        }
+        described_by
+    }
+
     fn common_component(
         &self,
+        id: Option<&str>,
         styles: &[<GridFormStyle as FormStyle>::StylingAttributes],
         parent_class: &'static str,
         inner: View,
     ) -> View {
         let mut width = 12;
         let mut tooltip = None;
-        for style in styles.iter() {
-            match style {
+        let mut help_text = None;
+        let mut class = String::from(parent_class);
+        let mut style = String::new();
+        for attr in styles.iter() {
+            match attr {
                 GFStyleAttr::Width(w) => width = *w,
+                GFStyleAttr::WidthAt(bp, w) => {
+                    class.push(' ');
+                    class.push_str(Self::breakpoint_class(*bp));
+                    style.push_str(&format!(" --gf-span-{}: {};", bp.bootstrap_infix(), w));
+                }
                 GFStyleAttr::Tooltip(t) => tooltip = Some(t),
+                GFStyleAttr::Class(c) => {
+                    class.push(' ');
+                    class.push_str(c);
+                }
+                GFStyleAttr::HelpText(t) => help_text = Some(t.clone()),
+                GFStyleAttr::Id(_) => {}
             }
         }
+        let style = format!("grid-column: span {};{}", width, style);
+        let help_id = id.map(help_id);
+        let help_text = help_text.map(|t| view! { <span class="form_help" id=help_id>{t}</span> });
 
         view! {
-            <div class=parent_class style:grid-column=format!("span {}", width) title=tooltip>
+            <div class=class style=style title=tooltip>
                 {inner}
+                {help_text}
             </div>
         }
         .into_view()
@@ -58,23 +127,36 @@ impl GridFormStyle {
 impl FormStyle for GridFormStyle {
     type StylingAttributes = GFStyleAttr;
 
+    /// Uses the [`GFStyleAttr::Id`] if there is one.
+    fn control_id(&self, styles: &[GFStyleAttr], id: &str) -> String {
+        styles
+            .iter()
+            .rev()
+            .find_map(|style| match style {
+                GFStyleAttr::Id(id) => Some(id.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| id.to_string())
+    }
+
     fn form_frame(&self, form: ControlRenderData<Self, View>) -> View {
         view! { <div class="form_grid">{form.data}</div> }.into_view()
     }
 
     /// A common function that wraps the given view in the styles
     fn custom_component(&self, styles: &[Self::StylingAttributes], inner: View) -> View {
-        self.common_component(styles, "custom_component_parent", inner)
+        self.common_component(None, styles, "custom_component_parent", inner)
     }
 
     fn group(&self, group: Rc<ControlRenderData<Self, View>>) -> View {
         let view = view! { <div class="form_group form_grid">{&group.data}</div> }.into_view();
 
-        self.common_component(&group.styles, "group_parent", view)
+        self.common_component(None, &group.styles, "group_parent", view)
     }
 
     fn spacer(&self, control: Rc<ControlRenderData<Self, SpacerData>>) -> View {
         self.common_component(
+            None,
             &control.styles,
             "spacer_parent",
             view! { <div style:height=control.data.height.as_ref()></div> }.into_view(),
@@ -97,7 +179,7 @@ impl FormStyle for GridFormStyle {
             H4 => view! { <h4 class="form_heading"> {title} </h4> }.into_view(),
         };
 
-        self.common_component(&control.styles, "heading_parent", view)
+        self.common_component(None, &control.styles, "heading_parent", view)
     }
 
     fn submit(
@@ -108,6 +190,7 @@ impl FormStyle for GridFormStyle {
         let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
 
         self.common_component(
+            None,
             &control.styles,
             "submit_parent",
             view! { <input type="submit" value=title class="form_submit"/> }.into_view(),
@@ -135,7 +218,7 @@ impl FormStyle for GridFormStyle {
         }
         .into_view();
 
-        self.common_component(&control.styles, "button_parent", view)
+        self.common_component(None, &control.styles, "button_parent", view)
     }
 
     fn output(
@@ -144,7 +227,23 @@ impl FormStyle for GridFormStyle {
         value_getter: Option<Signal<String>>,
     ) -> View {
         let view = view! { <span>{move || value_getter.map(|g| g.get())}</span> }.into_view();
-        self.common_component(&control.styles, "output_parent", view)
+        self.common_component(None, &control.styles, "output_parent", view)
+    }
+
+    fn review_item(
+        &self,
+        styles: &[Self::StylingAttributes],
+        label: String,
+        value: Signal<String>,
+    ) -> View {
+        let view = view! {
+            <dl class="form_review_item">
+                <dt class="form_label">{label}</dt>
+                <dd class="form_review_value">{value}</dd>
+            </dl>
+        }
+        .into_view();
+        self.common_component(None, styles, "review_item_parent", view)
     }
 
     fn hidden(
@@ -170,14 +269,19 @@ impl FormStyle for GridFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
         let input = view! {
             <input
                 type=control.data.input_type
-                id=&control.data.name
+                id=&id
                 name=&control.data.name
                 placeholder=control.data.placeholder.as_ref()
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
+                class=("form_input_warning", move || validation_state.get().is_warning())
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &id)
                 prop:value=move || value_getter.get()
             />
         };
@@ -196,16 +300,107 @@ impl FormStyle for GridFormStyle {
 
         let view = view! {
             <div>
-                <label for=&control.data.name class="form_label">
+                <label for=&id class="form_label">
                     {control.data.label.as_ref()}
                 </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+                <span
+                    class="form_error"
+                    class=("form_warning", move || validation_state.get().is_warning())
+                    id=error_id(&id)
+                    aria-live="polite"
+                >
+                    {move || validation_state.get().take_msg()}
+                </span>
             </div>
             {input}
         }
         .into_view();
 
-        self.common_component(&control.styles, "text_input_parent", view)
+        self.common_component(Some(&id), &control.styles, "text_input_parent", view)
+    }
+
+    fn password(
+        &self,
+        control: Rc<ControlRenderData<Self, PasswordData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
+        let shown = create_rw_signal(false);
+
+        let data = control.clone();
+        let toggle = control.data.reveal_labels.is_some().then(|| {
+            view! {
+                <button
+                    type="button"
+                    class="form_password_toggle"
+                    aria-controls=&id
+                    aria-pressed=move || shown.get().to_string()
+                    on:click=move |_| shown.update(|shown| *shown = !*shown)
+                >
+                    {move || data.data.reveal_label(shown.get()).map(String::from)}
+                </button>
+            }
+        });
+
+        let data = control.clone();
+        let strength = Signal::derive(move || data.data.strength(&value_getter.get()));
+        let meter = control.data.strength.is_some().then(|| {
+            view! {
+                <div class="form_strength" aria-live="polite">
+                    <meter
+                        class="form_strength_meter"
+                        min="0"
+                        max=PasswordStrength::MAX_SCORE
+                        value=move || strength.get().map(|s| s.score).unwrap_or_default()
+                    ></meter>
+                    <span class="form_strength_label">
+                        {move || strength.get().map(|s| s.label)}
+                    </span>
+                </div>
+            }
+        });
+
+        let view = view! {
+            <div>
+                <label for=&id class="form_label">
+                    {control.data.label.as_ref()}
+                </label>
+                <span
+                    class="form_error"
+                    class=("form_warning", move || validation_state.get().is_warning())
+                    id=error_id(&id)
+                    aria-live="polite"
+                >
+                    {move || validation_state.get().take_msg()}
+                </span>
+            </div>
+            <div class="form_password">
+                <input
+                    type=move || if shown.get() { "text" } else { "password" }
+                    id=&id
+                    name=&control.data.name
+                    placeholder=control.data.placeholder.as_ref()
+                    autocomplete=control.data.autocomplete
+                    class="form_input"
+                    class=("form_input_invalid", move || validation_state.get().is_err())
+                    class=("form_input_warning", move || validation_state.get().is_warning())
+                    aria-invalid=move || validation_state.get().is_err().to_string()
+                    aria-required=control.required.then_some("true")
+                    aria-describedby=Self::described_by(&control.styles, &id)
+                    prop:value=move || value_getter.get()
+                    on:input=move |ev| {
+                        value_setter.set(event_target_value(&ev));
+                    }
+                />
+                {toggle}
+            </div>
+            {meter}
+        }
+        .into_view();
+
+        self.common_component(Some(&id), &control.styles, "password_parent", view)
     }
 
     fn text_area(
@@ -215,15 +410,20 @@ impl FormStyle for GridFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
         let input = view! {
             <textarea
-                id=&control.data.name
+                id=&id
                 name=&control.data.name
                 placeholder=control.data.placeholder.as_ref()
                 prop:value=move || value_getter.get()
                 style="resize: vertical;"
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
+                class=("form_input_warning", move || validation_state.get().is_warning())
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &id)
             ></textarea>
         };
 
@@ -241,16 +441,23 @@ impl FormStyle for GridFormStyle {
 
         let view = view! {
             <div>
-                <label for=&control.data.name class="form_label">
+                <label for=&id class="form_label">
                     {control.data.label.as_ref()}
                 </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+                <span
+                    class="form_error"
+                    class=("form_warning", move || validation_state.get().is_warning())
+                    id=error_id(&id)
+                    aria-live="polite"
+                >
+                    {move || validation_state.get().take_msg()}
+                </span>
             </div>
             {input}
         }
         .into_view();
 
-        self.common_component(&control.styles, "text_area_parent", view)
+        self.common_component(Some(&id), &control.styles, "text_area_parent", view)
     }
 
     fn radio_buttons(
@@ -260,11 +467,14 @@ impl FormStyle for GridFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
         let buttons_view = control
             .data
             .options
             .iter()
-            .map(|(display, value)| {
+            .enumerate()
+            .map(|(i, (display, value))| {
+                let option_id = format!("{}-{}", id, i);
                 let display = display.clone();
                 let value = value.clone();
                 let value_clone = value.clone();
@@ -272,7 +482,7 @@ impl FormStyle for GridFormStyle {
                 view! {
                     <input
                         type="radio"
-                        id=&value
+                        id=&option_id
                         name=&control.data.name
                         value=&value
                         prop:checked=move || { value_getter.get() == value_clone }
@@ -284,7 +494,7 @@ impl FormStyle for GridFormStyle {
                         }
                     />
 
-                    <label for=&value>{display}</label>
+                    <label for=&option_id>{display}</label>
                     <br/>
                 }
             })
@@ -292,21 +502,34 @@ impl FormStyle for GridFormStyle {
 
         let view = view! {
             <div>
-                <label for=&control.data.name class="form_label">
+                <label id=format!("{}-label", id) class="form_label">
                     {control.data.label.as_ref()}
                 </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+                <span
+                    class="form_error"
+                    class=("form_warning", move || validation_state.get().is_warning())
+                    id=error_id(&id)
+                    aria-live="polite"
+                >
+                    {move || validation_state.get().take_msg()}
+                </span>
             </div>
             <div
+                role="radiogroup"
+                aria-labelledby=format!("{}-label", id)
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &id)
                 class="form_input"
                 class:form_input_invalid=move || validation_state.get().is_err()
+                class:form_input_warning=move || validation_state.get().is_warning()
             >
                 {buttons_view}
             </div>
         }
         .into_view();
 
-        self.common_component(&control.styles, "radio_buttons_parent", view)
+        self.common_component(Some(&id), &control.styles, "radio_buttons_parent", view)
     }
 
     fn select(
@@ -316,6 +539,7 @@ impl FormStyle for GridFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
         let control_clone = control.clone();
         let options_view = move || {
             control_clone
@@ -345,16 +569,27 @@ impl FormStyle for GridFormStyle {
 
         let view = view! {
             <div>
-                <label for=&control.data.name class="form_label">
+                <label for=&id class="form_label">
                     {control.data.label.as_ref()}
                 </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+                <span
+                    class="form_error"
+                    class=("form_warning", move || validation_state.get().is_warning())
+                    id=error_id(&id)
+                    aria-live="polite"
+                >
+                    {move || validation_state.get().take_msg()}
+                </span>
             </div>
             <select
-                id=&control.data.name
+                id=&id
                 name=&control.data.name
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
+                class=("form_input_warning", move || validation_state.get().is_warning())
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &id)
                 on:input=move |ev| {
                     value_setter.set(event_target_value(&ev));
                 }
@@ -365,7 +600,7 @@ impl FormStyle for GridFormStyle {
         }
         .into_view();
 
-        self.common_component(&control.styles, "select_parent", view)
+        self.common_component(Some(&id), &control.styles, "select_parent", view)
     }
 
     fn checkbox(
@@ -374,6 +609,7 @@ impl FormStyle for GridFormStyle {
         value_getter: Signal<bool>,
         value_setter: SignalSetter<bool>,
     ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
         let label = control
             .data
             .label
@@ -382,16 +618,17 @@ impl FormStyle for GridFormStyle {
 
         let view = view! {
             <label
-                for=&control.data.name
+                for=&id
                 class="form_checkbox"
                 class=("form_checkbox_checked", move || value_getter.get())
                 class=("form_checkbox_unchecked", move || !value_getter.get())
             >
                 <input
                     type="checkbox"
-                    id=&control.data.name
+                    id=&id
                     name=&control.data.name
                     style="margin: auto 0;"
+                    aria-required=control.required.then_some("true")
                     prop:checked=value_getter
                     on:input=move |ev| {
                         let new_value = event_target_checked(&ev);
@@ -403,7 +640,70 @@ impl FormStyle for GridFormStyle {
         }
         .into_view();
 
-        self.common_component(&control.styles, "checkbox_parent", view)
+        self.common_component(Some(&id), &control.styles, "checkbox_parent", view)
+    }
+
+    fn switch(
+        &self,
+        control: Rc<ControlRenderData<Self, SwitchData>>,
+        value_getter: Signal<bool>,
+        value_setter: SignalSetter<bool>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
+        let label = control
+            .data
+            .label
+            .clone()
+            .unwrap_or(control.data.name.clone());
+        let data = control.clone();
+        let state_label = move || data.data.state_label(value_getter.get()).map(String::from);
+
+        let view = view! {
+            <div>
+                <label for=&id class="form_label">
+                    {label}
+                </label>
+                <span
+                    class="form_error"
+                    class=("form_warning", move || validation_state.get().is_warning())
+                    id=error_id(&id)
+                    aria-live="polite"
+                >
+                    {move || validation_state.get().take_msg()}
+                </span>
+            </div>
+            <label
+                class="form_switch"
+                class=("form_switch_on", move || value_getter.get())
+                class=("form_switch_invalid", move || validation_state.get().is_err())
+                class=("form_switch_disabled", control.data.disabled)
+            >
+                <input
+                    type="checkbox"
+                    role="switch"
+                    id=&id
+                    name=&control.data.name
+                    class="form_switch_input"
+                    disabled=control.data.disabled
+                    aria-invalid=move || validation_state.get().is_err().to_string()
+                    aria-required=control.required.then_some("true")
+                    aria-describedby=Self::described_by(&control.styles, &id)
+                    prop:checked=value_getter
+                    on:input=move |ev| {
+                        let new_value = event_target_checked(&ev);
+                        value_setter.set(new_value);
+                    }
+                />
+                <span class="form_switch_track" aria-hidden="true">
+                    <span class="form_switch_thumb"></span>
+                </span>
+                <span class="form_switch_state">{state_label}</span>
+            </label>
+        }
+        .into_view();
+
+        self.common_component(Some(&id), &control.styles, "switch_parent", view)
     }
 
     fn stepper(
@@ -413,22 +713,34 @@ impl FormStyle for GridFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
         let view = view! {
             <div>
-                <label for=&control.data.name class="form_label">
+                <label for=&id class="form_label">
                     {control.data.label.as_ref()}
                 </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+                <span
+                    class="form_error"
+                    class=("form_warning", move || validation_state.get().is_warning())
+                    id=error_id(&id)
+                    aria-live="polite"
+                >
+                    {move || validation_state.get().take_msg()}
+                </span>
             </div>
             <input
                 type="number"
-                id=&control.data.name
+                id=&id
                 name=&control.data.name
                 step=control.data.step.clone()
                 min=control.data.min.clone()
                 max=control.data.max.clone()
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
+                class=("form_input_warning", move || validation_state.get().is_warning())
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &id)
                 prop:value=move || value_getter.get()
                 on:input=move |ev| {
                     value_setter.set(event_target_value(&ev));
@@ -437,7 +749,7 @@ impl FormStyle for GridFormStyle {
         }
         .into_view();
 
-        self.common_component(&control.styles, "stepper_parent", view)
+        self.common_component(Some(&id), &control.styles, "stepper_parent", view)
     }
 
     fn slider(
@@ -447,21 +759,33 @@ impl FormStyle for GridFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
         let view = view! {
             <div>
-                <label for=&control.data.name class="form_label">
+                <label for=&id class="form_label">
                     {control.data.label.as_ref()}
                 </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+                <span
+                    class="form_error"
+                    class=("form_warning", move || validation_state.get().is_warning())
+                    id=error_id(&id)
+                    aria-live="polite"
+                >
+                    {move || validation_state.get().take_msg()}
+                </span>
             </div>
             <input
                 type="range"
-                id=&control.data.name
+                id=&id
                 name=&control.data.name
                 min=control.data.min.clone()
                 max=control.data.max.clone()
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
+                class=("form_input_warning", move || validation_state.get().is_warning())
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &id)
                 prop:value=move || value_getter.get()
                 on:input=move |ev| {
                     let value = event_target_value(&ev);
@@ -471,7 +795,7 @@ impl FormStyle for GridFormStyle {
         }
         .into_view();
 
-        self.common_component(&control.styles, "slider_parent", view)
+        self.common_component(Some(&id), &control.styles, "slider_parent", view)
     }
 
     fn date(
@@ -481,21 +805,33 @@ impl FormStyle for GridFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> View {
+        let id = self.control_id(&control.styles, &control.id);
         let view = view! {
             <div>
-                <label for=&control.data.name class="form_label">
+                <label for=&id class="form_label">
                     {control.data.label.as_ref()}
                 </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+                <span
+                    class="form_error"
+                    class=("form_warning", move || validation_state.get().is_warning())
+                    id=error_id(&id)
+                    aria-live="polite"
+                >
+                    {move || validation_state.get().take_msg()}
+                </span>
             </div>
             <input
                 type="range"
-                id=&control.data.name
+                id=&id
                 name=&control.data.name
                 min=control.data.min.clone()
                 max=control.data.max.clone()
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
+                class=("form_input_warning", move || validation_state.get().is_warning())
+                aria-invalid=move || validation_state.get().is_err().to_string()
+                aria-required=control.required.then_some("true")
+                aria-describedby=Self::described_by(&control.styles, &id)
                 prop:value=move || value_getter.get()
                 on:input=move |ev| {
                     let value = event_target_value(&ev);
@@ -505,6 +841,6 @@ impl FormStyle for GridFormStyle {
         }
         .into_view();
 
-        self.common_component(&control.styles, "date_parent", view)
+        self.common_component(Some(&id), &control.styles, "date_parent", view)
     }
 }
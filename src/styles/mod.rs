@@ -1,23 +1,83 @@
+mod bootstrap;
 mod flowbite;
 mod grid_form;
 
 use crate::controls::{
     button::ButtonData, checkbox::CheckboxData, date::DateData, heading::HeadingData,
-    hidden::HiddenData, output::OutputData, radio_buttons::RadioButtonsData, select::SelectData,
-    slider::SliderData, spacer::SpacerData, stepper::StepperData, submit::SubmitData,
-    text_area::TextAreaData, text_input::TextInputData, ControlRenderData, ValidationState,
+    hidden::HiddenData, output::OutputData, password::PasswordData,
+    radio_buttons::RadioButtonsData, select::SelectData, slider::SliderData, spacer::SpacerData,
+    stepper::StepperData, submit::SubmitData, switch::SwitchData, text_area::TextAreaData,
+    text_input::TextInputData, ControlRenderData, ValidationState,
 };
-use leptos::{Signal, SignalSetter, View};
+use leptos::*;
 use std::rc::Rc;
+use web_sys::MouseEvent;
 
-pub use flowbite::{FbFormStyle, FbStyleAttr};
+pub use bootstrap::{BootstrapFormStyle, BsSize, BsStyleAttr};
+pub use flowbite::{FbFormStyle, FbStyleAttr, FbTheme};
 pub use grid_form::{GFStyleAttr, GridFormStyle};
 
+/// A responsive screen size breakpoint.
+///
+/// Styles that support responsive layouts can use this to apply a styling
+/// attribute only from a certain screen width and up.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Breakpoint {
+    /// Small screens, 576px (bootstrap) or 640px (tailwind) and up.
+    Sm,
+    /// Medium screens, 768px and up.
+    Md,
+    /// Large screens, 992px (bootstrap) or 1024px (tailwind) and up.
+    Lg,
+    /// Extra large screens, 1200px (bootstrap) or 1280px (tailwind) and up.
+    Xl,
+    /// Extra extra large screens, 1400px (bootstrap) or 1536px (tailwind)
+    /// and up.
+    Xxl,
+}
+
+impl Breakpoint {
+    /// The infix bootstrap uses for this breakpoint, as in `col-md-6`.
+    pub fn bootstrap_infix(&self) -> &'static str {
+        match self {
+            Breakpoint::Sm => "sm",
+            Breakpoint::Md => "md",
+            Breakpoint::Lg => "lg",
+            Breakpoint::Xl => "xl",
+            Breakpoint::Xxl => "xxl",
+        }
+    }
+}
+
+/// Gets the html id of the element showing a control's validation messages.
+///
+/// `id` is the id the style gives the control's input, after any overrides
+/// from the styling attributes.
+pub(crate) fn error_id(id: &str) -> String {
+    format!("{}-error", id)
+}
+
+/// Gets the html id of the element showing a control's help text.
+///
+/// `id` is the id the style gives the control's input, after any overrides
+/// from the styling attributes.
+pub(crate) fn help_id(id: &str) -> String {
+    format!("{}-help", id)
+}
+
 /// Defines a way to style a form.
 ///
 /// Provides methods for rendering all the controls.
 /// This provider is in charge of figuring out what html elements should be
 /// rendered and how they should be styled.
+///
+/// Only [`text_input`](Self::text_input), [`select`](Self::select) and
+/// [`checkbox`](Self::checkbox) need to be implemented. Every other control
+/// has a default rendering that falls back to a simpler control
+/// (a slider renders as a stepper, a stepper as a text input, and so on),
+/// or to plain html wrapped with
+/// [`custom_component`](Self::custom_component). Override the methods for
+/// the controls you want to render differently.
 pub trait FormStyle: 'static {
     /// The type of styling attributes that this [`FormStyle`] takes.
     ///
@@ -31,7 +91,25 @@ pub trait FormStyle: 'static {
     ///
     /// Do NOT wrap it in an actual `form` element; any
     /// wrapping should be done with `div` or similar elements.
-    fn form_frame(&self, form: ControlRenderData<Self, View>) -> View;
+    ///
+    /// By default, this wraps the form in a `div`.
+    fn form_frame(&self, form: ControlRenderData<Self, View>) -> View {
+        view! { <div>{form.data}</div> }.into_view()
+    }
+
+    /// Gets the html id that the control with the given generated `id` is
+    /// rendered with.
+    ///
+    /// The form uses this to find the control's elements, like to tell when
+    /// the control loses focus. Styles that let the id be overridden with a
+    /// styling attribute should return that id here, and render the control's
+    /// elements with this id, or ids that start with it followed by a `-`.
+    ///
+    /// By default, this returns the generated id.
+    fn control_id(&self, styles: &[Self::StylingAttributes], id: &str) -> String {
+        let _ = styles;
+        id.to_string()
+    }
 
     /// Wraps the view of a custom component.
     ///
@@ -42,62 +120,152 @@ pub trait FormStyle: 'static {
     /// This method does not need to be called by the custom component, but
     /// the custom component may make use of this method for the
     /// aforementioned reasons.
-    fn custom_component(&self, style: &[Self::StylingAttributes], inner: View) -> View;
+    ///
+    /// The default rendering of most controls is wrapped with this method.
+    /// By default, this returns the `inner` view unchanged.
+    fn custom_component(&self, style: &[Self::StylingAttributes], inner: View) -> View {
+        let _ = style;
+        inner
+    }
 
     /// Renders a group.
     ///
     /// The inner view for the group's components is provided.
     /// This method should wrap the group in any visual grouping elements,
     /// and apply the styles.
-    fn group(&self, group: Rc<ControlRenderData<Self, View>>) -> View;
+    ///
+    /// By default, this wraps the group in a `div`.
+    fn group(&self, group: Rc<ControlRenderData<Self, View>>) -> View {
+        let view = view! { <div>{&group.data}</div> }.into_view();
+        self.custom_component(&group.styles, view)
+    }
 
     /// Renders a spacer.
     ///
     /// See [`SpacerData`].
-    fn spacer(&self, control: Rc<ControlRenderData<Self, SpacerData>>) -> View;
+    ///
+    /// By default, this renders an empty `div` with the spacer's height.
+    fn spacer(&self, control: Rc<ControlRenderData<Self, SpacerData>>) -> View {
+        let view = view! { <div style:height=control.data.height.as_ref()></div> }.into_view();
+        self.custom_component(&control.styles, view)
+    }
 
     /// Renders a heading for a section of the form.
+    ///
+    /// By default, this renders a plain html heading.
     fn heading(
         &self,
         control: Rc<ControlRenderData<Self, HeadingData>>,
         value_getter: Option<Signal<String>>,
-    ) -> View;
+    ) -> View {
+        use crate::controls::heading::HeadingLevel::*;
+
+        let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
+
+        let view = match control.data.level {
+            H1 => view! { <h1>{title}</h1> }.into_view(),
+            H2 => view! { <h2>{title}</h2> }.into_view(),
+            H3 => view! { <h3>{title}</h3> }.into_view(),
+            H4 => view! { <h4>{title}</h4> }.into_view(),
+        };
+
+        self.custom_component(&control.styles, view)
+    }
 
     /// Renders a submit button.
     ///
     /// See [`SubmitData`].
+    ///
+    /// By default, this renders a plain submit input.
     fn submit(
         &self,
         control: Rc<ControlRenderData<Self, SubmitData>>,
         value_getter: Option<Signal<String>>,
-    ) -> View;
+    ) -> View {
+        let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
+        let view = view! { <input type="submit" value=title/> }.into_view();
+        self.custom_component(&control.styles, view)
+    }
 
     /// Renders a button.
     ///
     /// See [`ButtonData`]
+    ///
+    /// By default, this renders a plain button.
     fn button(
         &self,
         control: Rc<ControlRenderData<Self, ButtonData>>,
         value_getter: Option<Signal<String>>,
-    ) -> View;
+    ) -> View {
+        let action = control.data.action.clone();
+        let on_click = move |ev: MouseEvent| {
+            if let Some(ref action) = action {
+                action(ev)
+            }
+        };
+
+        let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
+
+        let view = view! {
+            <button type="button" on:click=on_click>
+                {title}
+            </button>
+        }
+        .into_view();
+
+        self.custom_component(&control.styles, view)
+    }
 
     /// Renders some output text.
     ///
     /// See [`OutputData`].
+    ///
+    /// By default, this renders the text in a `span`.
     fn output(
         &self,
         control: Rc<ControlRenderData<Self, OutputData>>,
         value_getter: Option<Signal<String>>,
-    ) -> View;
+    ) -> View {
+        let view = view! { <span>{move || value_getter.map(|g| g.get())}</span> }.into_view();
+        self.custom_component(&control.styles, view)
+    }
+
+    /// Renders a control's label and its formatted value, for reviewing the
+    /// form before submitting it.
+    ///
+    /// The styles are the control's styling attributes. See
+    /// [`get_review`](crate::FormToolData::get_review).
+    ///
+    /// By default, this renders the label and value as a description list.
+    fn review_item(
+        &self,
+        styles: &[Self::StylingAttributes],
+        label: String,
+        value: Signal<String>,
+    ) -> View {
+        let view = view! {
+            <dl>
+                <dt>{label}</dt>
+                <dd>{value}</dd>
+            </dl>
+        }
+        .into_view();
+        self.custom_component(styles, view)
+    }
 
     /// Renders a input control that should be hidden from the user.
     ///
     /// See [`HiddenData`].
+    ///
+    /// By default, this renders a hidden input.
     fn hidden(
         &self,
         control: Rc<ControlRenderData<Self, HiddenData>>,
         value_getter: Option<Signal<String>>,
-    ) -> View;
+    ) -> View {
+        let value_getter = move || value_getter.map(|g| g.get());
+        view! { <input type="hidden" name=&control.data.name prop:value=value_getter/> }.into_view()
+    }
 
     /// Renders a text input control.
     ///
@@ -113,24 +281,65 @@ pub trait FormStyle: 'static {
     /// Renders a text area control.
     ///
     /// See [`TextAreaData`].
+    ///
+    /// By default, this falls back to [`text_input`](Self::text_input).
     fn text_area(
         &self,
         control: Rc<ControlRenderData<Self, TextAreaData>>,
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
-    ) -> View;
+    ) -> View {
+        let new_control = ControlRenderData {
+            styles: control.styles.clone(),
+            id: control.id.clone(),
+            required: control.required,
+            data: TextInputData {
+                name: control.data.name.clone(),
+                label: control.data.label.clone(),
+                placeholder: control.data.placeholder.clone(),
+                input_type: "text",
+                update_event: control.data.update_event,
+            },
+        };
+        self.text_input(
+            Rc::new(new_control),
+            value_getter,
+            value_setter,
+            validation_state,
+        )
+    }
 
     /// Renders a group of radio buttons.
     ///
     /// See [`RadioButtonsData`].
+    ///
+    /// By default, this falls back to [`select`](Self::select).
     fn radio_buttons(
         &self,
         control: Rc<ControlRenderData<Self, RadioButtonsData>>,
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
-    ) -> View;
+    ) -> View {
+        let new_control = ControlRenderData {
+            styles: control.styles.clone(),
+            id: control.id.clone(),
+            required: control.required,
+            data: SelectData {
+                name: control.data.name.clone(),
+                label: control.data.label.clone(),
+                options: MaybeSignal::Static(control.data.options.clone()),
+                blank_option: None,
+            },
+        };
+        self.select(
+            Rc::new(new_control),
+            value_getter,
+            value_setter,
+            validation_state,
+        )
+    }
 
     /// Renders a select (or dropdown) control.
     ///
@@ -153,36 +362,160 @@ pub trait FormStyle: 'static {
         value_setter: SignalSetter<bool>,
     ) -> View;
 
+    /// Renders a password control.
+    ///
+    /// See [`PasswordData`].
+    ///
+    /// By default, this falls back to a "password"
+    /// [`text_input`](Self::text_input). The reveal toggle and the strength
+    /// meter are not shown by the fallback.
+    fn password(
+        &self,
+        control: Rc<ControlRenderData<Self, PasswordData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let new_control = ControlRenderData {
+            styles: control.styles.clone(),
+            id: control.id.clone(),
+            required: control.required,
+            data: TextInputData {
+                name: control.data.name.clone(),
+                label: control.data.label.clone(),
+                placeholder: control.data.placeholder.clone(),
+                input_type: "password",
+                ..Default::default()
+            },
+        };
+        self.text_input(
+            Rc::new(new_control),
+            value_getter,
+            value_setter,
+            validation_state,
+        )
+    }
+
+    /// Renders a switch control.
+    ///
+    /// See [`SwitchData`].
+    ///
+    /// By default, this falls back to a [`checkbox`](Self::checkbox). The
+    /// on and off labels, the disabled state and the validation state are
+    /// not respected by the fallback.
+    fn switch(
+        &self,
+        control: Rc<ControlRenderData<Self, SwitchData>>,
+        value_getter: Signal<bool>,
+        value_setter: SignalSetter<bool>,
+        validation_state: Signal<ValidationState>,
+    ) -> View {
+        let _ = validation_state;
+        let new_control = ControlRenderData {
+            styles: control.styles.clone(),
+            id: control.id.clone(),
+            required: control.required,
+            data: CheckboxData {
+                name: control.data.name.clone(),
+                label: control.data.label.clone(),
+            },
+        };
+        self.checkbox(Rc::new(new_control), value_getter, value_setter)
+    }
+
     /// Renders a stepper control.
     ///
     /// See [`StepperData`].
+    ///
+    /// By default, this falls back to a "number" [`text_input`](Self::text_input).
+    /// The step, minimum and maximum are not respected by the fallback.
     fn stepper(
         &self,
         control: Rc<ControlRenderData<Self, StepperData>>,
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
-    ) -> View;
+    ) -> View {
+        let new_control = ControlRenderData {
+            styles: control.styles.clone(),
+            id: control.id.clone(),
+            required: control.required,
+            data: TextInputData {
+                name: control.data.name.clone(),
+                label: control.data.label.clone(),
+                input_type: "number",
+                ..Default::default()
+            },
+        };
+        self.text_input(
+            Rc::new(new_control),
+            value_getter,
+            value_setter,
+            validation_state,
+        )
+    }
 
     /// Renders a slider control.
     ///
     /// See [`SliderData`].
+    ///
+    /// By default, this falls back to [`stepper`](Self::stepper).
     fn slider(
         &self,
         control: Rc<ControlRenderData<Self, SliderData>>,
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
-    ) -> View;
+    ) -> View {
+        let new_control = ControlRenderData {
+            styles: control.styles.clone(),
+            id: control.id.clone(),
+            required: control.required,
+            data: StepperData {
+                name: control.data.name.clone(),
+                label: control.data.label.clone(),
+                step: control.data.step.clone(),
+                min: control.data.min.clone(),
+                max: control.data.max.clone(),
+            },
+        };
+        self.stepper(
+            Rc::new(new_control),
+            value_getter,
+            value_setter,
+            validation_state,
+        )
+    }
 
     /// Renders a slider control.
     ///
     /// See [`DatetimeData`].
+    ///
+    /// By default, this falls back to a "date" [`text_input`](Self::text_input).
+    /// The minimum and maximum are not respected by the fallback.
     fn date(
         &self,
         control: Rc<ControlRenderData<Self, DateData>>,
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
-    ) -> View;
+    ) -> View {
+        let new_control = ControlRenderData {
+            styles: control.styles.clone(),
+            id: control.id.clone(),
+            required: control.required,
+            data: TextInputData {
+                name: control.data.name.clone(),
+                label: control.data.label.clone(),
+                input_type: "date",
+                ..Default::default()
+            },
+        };
+        self.text_input(
+            Rc::new(new_control),
+            value_getter,
+            value_setter,
+            validation_state,
+        )
+    }
 }
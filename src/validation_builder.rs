@@ -1,11 +1,80 @@
-use crate::{controls::ValidationFn, FormToolData};
-use std::fmt::Display;
+use crate::{
+    controls::ValidationFn,
+    messages::{DefaultMessages, MessageCatalog, ValidationMessage},
+    FormToolData,
+};
+use std::{fmt::Display, marker::PhantomData, ops::RangeInclusive, rc::Rc, str::FromStr};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A class of characters that a field can be required to contain, like for
+/// password rules.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CharacterClass {
+    /// A lowercase letter.
+    Lowercase,
+    /// An uppercase letter.
+    Uppercase,
+    /// A digit.
+    Digit,
+    /// Any character that is not a letter, digit or whitespace.
+    Symbol,
+}
+
+impl CharacterClass {
+    /// All the character classes.
+    pub const ALL: [CharacterClass; 4] = [
+        CharacterClass::Lowercase,
+        CharacterClass::Uppercase,
+        CharacterClass::Digit,
+        CharacterClass::Symbol,
+    ];
+
+    /// Checks if the character is in this class.
+    pub fn matches(&self, c: char) -> bool {
+        match self {
+            CharacterClass::Lowercase => c.is_lowercase(),
+            CharacterClass::Uppercase => c.is_uppercase(),
+            CharacterClass::Digit => c.is_numeric(),
+            CharacterClass::Symbol => !c.is_alphanumeric() && !c.is_whitespace(),
+        }
+    }
+
+    /// Counts how many of the character classes the value contains.
+    pub fn count_in(value: &str) -> usize {
+        CharacterClass::ALL
+            .iter()
+            .filter(|class| value.chars().any(|c| class.matches(c)))
+            .count()
+    }
+}
 
 /// A function that validates a field.
 ///
 /// This is similar to [`ValidationFn`](crate::controls::ValidationFn)
-/// but takes a &str for the name of the field for improved error messages.
-type ValidationBuilderFn<T> = dyn Fn(&str, &T) -> Result<(), String> + 'static;
+/// but also takes the field's value, and returns a structured
+/// [`ValidationMessage`], which is rendered by the builder's
+/// [`MessageCatalog`].
+type ValidationBuilderFn<FD, T> = dyn Fn(&FD, &T) -> Result<(), ValidationMessage> + 'static;
+
+/// A function that gets the field to validate from the form data.
+type FieldFn<FD, T> = dyn Fn(&FD) -> &T + 'static;
+
+/// Marks a [`ValidationBuilder`] that validates a field of the form data,
+/// made with [`for_field`](ValidationBuilder::for_field).
+///
+/// Only these builders have a [`build`](ValidationBuilder::build) method.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FieldRules;
+
+/// Marks a [`ValidationBuilder`] for the rules applied to the inside of a
+/// field, like by [`some`](ValidationBuilder::some),
+/// [`each`](ValidationBuilder::each) and
+/// [`as_str`](ValidationBuilder::as_str).
+///
+/// These builders have no field of their own, so they can't be built.
+/// Their rules are run by the builder they are nested in.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NestedRules;
 
 /// A helper builder that allows you to specify a validation function
 /// declaritivly
@@ -14,13 +83,23 @@ type ValidationBuilderFn<T> = dyn Fn(&str, &T) -> Result<(), String> + 'static;
 /// closures, but for simple validation function this builder can be helpful
 ///
 /// Validations are run in the order that they are called in the builder.
-pub struct ValidationBuilder<FD: FormToolData, T: ?Sized + 'static> {
+///
+/// `K` is either [`FieldRules`] or [`NestedRules`], depending on how the
+/// builder was made.
+pub struct ValidationBuilder<FD: FormToolData, T: ?Sized + 'static, K = FieldRules> {
     /// The name of the field, for error messages.
     name: String,
     /// The getter function for the field to validate.
-    field_fn: Box<dyn Fn(&FD) -> &T + 'static>,
+    ///
+    /// This is always set for [`FieldRules`] builders, and never set for
+    /// [`NestedRules`] builders.
+    field_fn: Option<Box<FieldFn<FD, T>>>,
+    /// The catalog used to render the error messages.
+    messages: Rc<dyn MessageCatalog>,
     /// The functions to be called when validating.
-    functions: Vec<Box<ValidationBuilderFn<T>>>,
+    functions: Vec<Box<ValidationBuilderFn<FD, T>>>,
+    /// The kind of builder.
+    kind: PhantomData<K>,
 }
 
 impl<FD: FormToolData, T: ?Sized + 'static> ValidationBuilder<FD, T> {
@@ -28,8 +107,69 @@ impl<FD: FormToolData, T: ?Sized + 'static> ValidationBuilder<FD, T> {
     pub fn for_field(field_fn: impl Fn(&FD) -> &T + 'static) -> Self {
         ValidationBuilder {
             name: String::from("Field"),
-            field_fn: Box::new(field_fn),
+            field_fn: Some(Box::new(field_fn)),
+            messages: Rc::new(DefaultMessages),
             functions: Vec::new(),
+            kind: PhantomData,
+        }
+    }
+
+    /// Builds the action validation function.
+    pub fn build(self) -> impl ValidationFn<FD> {
+        let field_fn = self
+            .field_fn
+            .expect("field builders are always made with a field");
+        move |form_data| {
+            let value = field_fn(form_data);
+            for f in self.functions.iter() {
+                if let Err(msg) = f(form_data, value) {
+                    return Err(self.messages.message(&self.name, &msg));
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+impl<FD: FormToolData, T: ?Sized + 'static, K> ValidationBuilder<FD, T, K> {
+    /// Creates a new empty [`NestedRules`] builder for the rules applied to
+    /// the inside of this field.
+    ///
+    /// It starts with the name and catalog of this builder.
+    fn nested<U: ?Sized + 'static>(&self) -> ValidationBuilder<FD, U, NestedRules> {
+        ValidationBuilder {
+            name: self.name.clone(),
+            field_fn: None,
+            messages: self.messages.clone(),
+            functions: Vec::new(),
+            kind: PhantomData,
+        }
+    }
+
+    /// Combines the rules of a nested builder into one function.
+    ///
+    /// If the nested builder was given its own name or catalog, its errors
+    /// are rendered with them, and passed on as
+    /// [`ValidationMessage::Custom`].
+    fn nested_rules<U: ?Sized + 'static>(
+        &self,
+        nested: ValidationBuilder<FD, U, NestedRules>,
+    ) -> Box<ValidationBuilderFn<FD, U>> {
+        let ValidationBuilder {
+            name,
+            messages,
+            functions,
+            ..
+        } = nested;
+        let run = move |fd: &FD, value: &U| functions.iter().try_for_each(|f| f(fd, value));
+
+        if name == self.name && Rc::ptr_eq(&messages, &self.messages) {
+            Box::new(run)
+        } else {
+            Box::new(move |fd, value| {
+                run(fd, value)
+                    .map_err(|message| ValidationMessage::Custom(messages.message(&name, &message)))
+            })
         }
     }
 
@@ -41,50 +181,162 @@ impl<FD: FormToolData, T: ?Sized + 'static> ValidationBuilder<FD, T> {
         self
     }
 
+    /// Sets the [`MessageCatalog`] used to render the error messages.
+    ///
+    /// This defaults to the english [`DefaultMessages`].
+    pub fn messages(mut self, catalog: impl MessageCatalog) -> Self {
+        self.messages = Rc::new(catalog);
+        self
+    }
+
     /// Adds a custom validation function.
     ///
     /// The function should take the value as an argument and return
     /// a [`Result<(), String>`], just like any other validation function.
+    /// The error message is shown as is.
     pub fn custom(mut self, f: impl ValidationFn<T>) -> Self {
-        self.functions.push(Box::new(move |_name, value| f(value)));
+        self.functions.push(Box::new(move |_fd, value| {
+            f(value).map_err(ValidationMessage::Custom)
+        }));
         self
     }
 
-    /// Builds the action validation function.
-    pub fn build(self) -> impl ValidationFn<FD> {
-        move |form_data| {
-            let value = (self.field_fn)(form_data);
-            for f in self.functions.iter() {
-                match f(self.name.as_str(), value) {
-                    Ok(()) => {}
-                    err => return err,
-                }
+    /// Adds a custom validation function that returns a structured
+    /// [`ValidationMessage`], to be rendered by the [`MessageCatalog`].
+    pub fn custom_message(
+        mut self,
+        f: impl Fn(&T) -> Result<(), ValidationMessage> + 'static,
+    ) -> Self {
+        self.functions.push(Box::new(move |_fd, value| f(value)));
+        self
+    }
+
+    /// Requires the field to be equal to another field, like a password
+    /// confirmation field.
+    ///
+    /// `other_name` is the name of the other field, for error messages.
+    pub fn equals_field<U: ?Sized + 'static>(
+        mut self,
+        other: impl Fn(&FD) -> &U + 'static,
+        other_name: impl ToString,
+    ) -> Self
+    where
+        T: PartialEq<U>,
+    {
+        let other_name = other_name.to_string();
+        self.functions.push(Box::new(move |fd, value| {
+            if value != other(fd) {
+                Err(ValidationMessage::EqualsField(other_name.clone()))
+            } else {
+                Ok(())
             }
-            Ok(())
-        }
+        }));
+        self
+    }
+
+    /// Requires the field to not be equal to another field, like a new
+    /// password that must differ from the old password.
+    ///
+    /// `other_name` is the name of the other field, for error messages.
+    pub fn not_equal_field<U: ?Sized + 'static>(
+        mut self,
+        other: impl Fn(&FD) -> &U + 'static,
+        other_name: impl ToString,
+    ) -> Self
+    where
+        T: PartialEq<U>,
+    {
+        let other_name = other_name.to_string();
+        self.functions.push(Box::new(move |fd, value| {
+            if value == other(fd) {
+                Err(ValidationMessage::NotEqualField(other_name.clone()))
+            } else {
+                Ok(())
+            }
+        }));
+        self
     }
 }
 
-impl<FD: FormToolData, T> ValidationBuilder<FD, Option<T>> {
+impl<FD: FormToolData, T: 'static, K> ValidationBuilder<FD, Option<T>, K> {
     /// Requires the field to be `Some`.
     pub fn required(mut self) -> Self {
-        self.functions.push(Box::new(move |name, value| {
+        self.functions.push(Box::new(move |_fd, value| {
             if value.is_none() {
-                Err(format!("{} is required", name))
+                Err(ValidationMessage::Required)
             } else {
                 Ok(())
             }
         }));
         self
     }
+
+    /// Requires the field to be `Some` when another field is set.
+    ///
+    /// `other_is_set` should return whether the other field is set, and
+    /// `other_name` is the name of the other field, for error messages.
+    pub fn required_if_set(
+        mut self,
+        other_is_set: impl Fn(&FD) -> bool + 'static,
+        other_name: impl ToString,
+    ) -> Self {
+        let other_name = other_name.to_string();
+        self.functions.push(Box::new(move |fd, value| {
+            if value.is_none() && other_is_set(fd) {
+                Err(ValidationMessage::RequiredIfSet(other_name.clone()))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Applies the rules built by `rules` to the value, if it is `Some`.
+    ///
+    /// `None` values pass, so use [`required`](Self::required) as well if the
+    /// field is required.
+    pub fn some(
+        mut self,
+        rules: impl FnOnce(
+            ValidationBuilder<FD, T, NestedRules>,
+        ) -> ValidationBuilder<FD, T, NestedRules>,
+    ) -> Self {
+        let inner = self.nested_rules(rules(self.nested()));
+        self.functions.push(Box::new(move |fd, value| match value {
+            Some(value) => inner(fd, value),
+            None => Ok(()),
+        }));
+        self
+    }
+}
+
+impl<FD: FormToolData, K> ValidationBuilder<FD, String, K> {
+    /// Applies the rules built by `rules` to the value as a `str`.
+    ///
+    /// This gives access to the `str` rules, like
+    /// [`min_len`](ValidationBuilder::min_len), for `String` values inside
+    /// of a field, like with [`some`](ValidationBuilder::some) on an
+    /// `Option<String>` or [`each`](ValidationBuilder::each) on a
+    /// `Vec<String>`.
+    pub fn as_str(
+        mut self,
+        rules: impl FnOnce(
+            ValidationBuilder<FD, str, NestedRules>,
+        ) -> ValidationBuilder<FD, str, NestedRules>,
+    ) -> Self {
+        let inner = self.nested_rules(rules(self.nested()));
+        self.functions
+            .push(Box::new(move |fd, value| inner(fd, value.as_str())));
+        self
+    }
 }
 
-impl<FD: FormToolData> ValidationBuilder<FD, str> {
+impl<FD: FormToolData, K> ValidationBuilder<FD, str, K> {
     /// Requires the field to not be empty.
     pub fn required(mut self) -> Self {
-        self.functions.push(Box::new(move |name, value| {
+        self.functions.push(Box::new(move |_fd, value| {
             if value.is_empty() {
-                Err(format!("{} is required", name))
+                Err(ValidationMessage::Required)
             } else {
                 Ok(())
             }
@@ -92,11 +344,34 @@ impl<FD: FormToolData> ValidationBuilder<FD, str> {
         self
     }
 
-    /// Requires the field's length to be at least `min_len`.
+    /// Requires the field to not be empty when another field is set.
+    ///
+    /// `other_is_set` should return whether the other field is set, and
+    /// `other_name` is the name of the other field, for error messages.
+    pub fn required_if_set(
+        mut self,
+        other_is_set: impl Fn(&FD) -> bool + 'static,
+        other_name: impl ToString,
+    ) -> Self {
+        let other_name = other_name.to_string();
+        self.functions.push(Box::new(move |fd, value| {
+            if value.is_empty() && other_is_set(fd) {
+                Err(ValidationMessage::RequiredIfSet(other_name.clone()))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to have at least `min_len` characters.
+    ///
+    /// This counts [`char`]s, not bytes. To count what the user sees as
+    /// characters, see [`min_graphemes`](Self::min_graphemes).
     pub fn min_len(mut self, min_len: usize) -> Self {
-        self.functions.push(Box::new(move |name, value| {
-            if value.len() < min_len {
-                Err(format!("{} must be >= {} characters", name, min_len))
+        self.functions.push(Box::new(move |_fd, value| {
+            if value.chars().count() < min_len {
+                Err(ValidationMessage::MinLen(min_len))
             } else {
                 Ok(())
             }
@@ -104,11 +379,14 @@ impl<FD: FormToolData> ValidationBuilder<FD, str> {
         self
     }
 
-    /// Requires the field's length to be less than or equal to `min_len`.
+    /// Requires the field to have at most `max_len` characters.
+    ///
+    /// This counts [`char`]s, not bytes. To count what the user sees as
+    /// characters, see [`max_graphemes`](Self::max_graphemes).
     pub fn max_len(mut self, max_len: usize) -> Self {
-        self.functions.push(Box::new(move |name, value| {
-            if value.len() > max_len {
-                Err(format!("{} must be <= {} characters", name, max_len))
+        self.functions.push(Box::new(move |_fd, value| {
+            if value.chars().count() > max_len {
+                Err(ValidationMessage::MaxLen(max_len))
             } else {
                 Ok(())
             }
@@ -119,24 +397,327 @@ impl<FD: FormToolData> ValidationBuilder<FD, str> {
     /// Requires the field to contain `pattern`.
     pub fn contains(mut self, pattern: impl ToString) -> Self {
         let pattern = pattern.to_string();
-        self.functions.push(Box::new(move |name, value| {
+        self.functions.push(Box::new(move |_fd, value| {
             if !value.contains(&pattern) {
-                Err(format!("{} must contain {}", name, &pattern))
+                Err(ValidationMessage::Contains(pattern.clone()))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to have at least `min_len` grapheme clusters.
+    ///
+    /// Grapheme clusters are what the user sees as a single character, like
+    /// an emoji made up of multiple [`char`]s.
+    pub fn min_graphemes(mut self, min_len: usize) -> Self {
+        self.functions.push(Box::new(move |_fd, value| {
+            if value.graphemes(true).count() < min_len {
+                Err(ValidationMessage::MinLen(min_len))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to have at most `max_len` grapheme clusters.
+    ///
+    /// Grapheme clusters are what the user sees as a single character, like
+    /// an emoji made up of multiple [`char`]s.
+    pub fn max_graphemes(mut self, max_len: usize) -> Self {
+        self.functions.push(Box::new(move |_fd, value| {
+            if value.graphemes(true).count() > max_len {
+                Err(ValidationMessage::MaxLen(max_len))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to start with `prefix`.
+    pub fn starts_with(mut self, prefix: impl ToString) -> Self {
+        let prefix = prefix.to_string();
+        self.functions.push(Box::new(move |_fd, value| {
+            if !value.starts_with(&prefix) {
+                Err(ValidationMessage::StartsWith(prefix.clone()))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to end with `suffix`.
+    pub fn ends_with(mut self, suffix: impl ToString) -> Self {
+        let suffix = suffix.to_string();
+        self.functions.push(Box::new(move |_fd, value| {
+            if !value.ends_with(&suffix) {
+                Err(ValidationMessage::EndsWith(suffix.clone()))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to only contain ASCII characters.
+    pub fn ascii(mut self) -> Self {
+        self.functions.push(Box::new(move |_fd, value| {
+            if !value.is_ascii() {
+                Err(ValidationMessage::Ascii)
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to only contain alphanumeric characters.
+    ///
+    /// This uses [`char::is_alphanumeric`], so letters and numbers from
+    /// any language are allowed. Use this with [`ascii`](Self::ascii) to
+    /// only allow `a-z`, `A-Z` and `0-9`.
+    pub fn alphanumeric(mut self) -> Self {
+        self.functions.push(Box::new(move |_fd, value| {
+            if !value.chars().all(char::is_alphanumeric) {
+                Err(ValidationMessage::Alphanumeric)
             } else {
                 Ok(())
             }
         }));
         self
     }
+
+    /// Requires the field to contain at least one character of the given
+    /// [`CharacterClass`].
+    ///
+    /// Empty values pass, so use [`required`](Self::required) as well if
+    /// the field is required.
+    pub fn require_class(mut self, class: CharacterClass) -> Self {
+        self.functions.push(Box::new(move |_fd, value| {
+            if !value.is_empty() && !value.chars().any(|c| class.matches(c)) {
+                Err(ValidationMessage::MissingCharacterClass(class))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to contain characters from at least `min_classes`
+    /// of the [`CharacterClass`]es, like a password that needs 3 of
+    /// lowercase letters, uppercase letters, digits and symbols.
+    ///
+    /// Empty values pass, so use [`required`](Self::required) as well if
+    /// the field is required.
+    pub fn min_classes(mut self, min_classes: usize) -> Self {
+        self.functions.push(Box::new(move |_fd, value| {
+            if !value.is_empty() && CharacterClass::count_in(value) < min_classes {
+                Err(ValidationMessage::MinCharacterClasses(min_classes))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to look like an email address.
+    ///
+    /// This only checks the basic shape of the address (`local@domain.tld`),
+    /// not whether it can actually receive email. Empty values pass, so
+    /// use [`required`](Self::required) as well if the field is required.
+    pub fn email(mut self) -> Self {
+        self.functions.push(Box::new(move |_fd, value| {
+            if !value.is_empty() && !is_email(value) {
+                Err(ValidationMessage::Email)
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to look like an absolute URL, like
+    /// `https://example.com`.
+    ///
+    /// Empty values pass, so use [`required`](Self::required) as well if the
+    /// field is required.
+    pub fn url(mut self) -> Self {
+        self.functions.push(Box::new(move |_fd, value| {
+            if !value.is_empty() && !is_url(value) {
+                Err(ValidationMessage::Url)
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to parse as a number of type `N` within `range`.
+    ///
+    /// This is useful for numbers that are stored as strings. Empty values
+    /// pass, so use [`required`](Self::required) as well if the field is
+    /// required.
+    pub fn number_in_range<N>(mut self, range: RangeInclusive<N>) -> Self
+    where
+        N: FromStr + PartialOrd + Display + 'static,
+    {
+        self.functions.push(Box::new(move |_fd, value| {
+            let value = value.trim();
+            if value.is_empty() {
+                return Ok(());
+            }
+            let number = value
+                .parse::<N>()
+                .map_err(|_| ValidationMessage::NotANumber)?;
+            check_range(&number, &range)
+        }));
+        self
+    }
+
+    /// Requires the field to match the regular expression `regex`.
+    ///
+    /// The whole field doesn't need to match unless the expression is
+    /// anchored with `^` and `$`. Empty values pass, so use
+    /// [`required`](Self::required) as well if the field is required.
+    #[cfg(feature = "regex")]
+    pub fn matches(mut self, regex: regex::Regex) -> Self {
+        self.functions.push(Box::new(move |_fd, value| {
+            if !value.is_empty() && !regex.is_match(value) {
+                Err(ValidationMessage::Pattern(regex.as_str().to_string()))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+}
+
+impl<FD: FormToolData, T: 'static, K> ValidationBuilder<FD, Vec<T>, K> {
+    /// Requires the field to have at least `min_items` items.
+    pub fn min_items(mut self, min_items: usize) -> Self {
+        self.functions.push(Box::new(move |_fd, value| {
+            if value.len() < min_items {
+                Err(ValidationMessage::MinItems(min_items))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to have at most `max_items` items.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.functions.push(Box::new(move |_fd, value| {
+            if value.len() > max_items {
+                Err(ValidationMessage::MaxItems(max_items))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires every item to be unique according to `PartialEq`.
+    ///
+    /// The error holds the index of the first repeated item.
+    pub fn unique(mut self) -> Self
+    where
+        T: PartialEq,
+    {
+        self.functions.push(Box::new(move |_fd, value| {
+            for (i, item) in value.iter().enumerate() {
+                if value[..i].contains(item) {
+                    return Err(ValidationMessage::Duplicate(i));
+                }
+            }
+            Ok(())
+        }));
+        self
+    }
+
+    /// Applies the rules built by `rules` to each item.
+    ///
+    /// Errors are wrapped in [`ValidationMessage::Item`] with the index of
+    /// the failing item.
+    pub fn each(
+        mut self,
+        rules: impl FnOnce(
+            ValidationBuilder<FD, T, NestedRules>,
+        ) -> ValidationBuilder<FD, T, NestedRules>,
+    ) -> Self {
+        let inner = self.nested_rules(rules(self.nested()));
+        self.functions.push(Box::new(move |fd, value| {
+            for (index, item) in value.iter().enumerate() {
+                inner(fd, item).map_err(|message| ValidationMessage::Item {
+                    index,
+                    message: Box::new(message),
+                })?;
+            }
+            Ok(())
+        }));
+        self
+    }
+}
+
+/// Checks that the number is within the range.
+///
+/// Values that can't be compared with the range, like `NaN`, are out of
+/// range. They are reported as below the minimum.
+fn check_range<N: PartialOrd + Display>(
+    number: &N,
+    range: &RangeInclusive<N>,
+) -> Result<(), ValidationMessage> {
+    if range.contains(number) {
+        Ok(())
+    } else if number > range.end() {
+        Err(ValidationMessage::MaxValue(range.end().to_string()))
+    } else {
+        Err(ValidationMessage::MinValue(range.start().to_string()))
+    }
 }
 
-impl<FD: FormToolData, T: PartialOrd<T> + Display + 'static> ValidationBuilder<FD, T> {
+/// Checks that the value has the shape of an email address.
+///
+/// Only one `@` is allowed, so the local part can't contain one.
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+
+    !local.is_empty()
+        && !value.chars().any(char::is_whitespace)
+        && domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+/// Checks that the value has the shape of an absolute URL.
+fn is_url(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once("://") else {
+        return false;
+    };
+
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && !host.is_empty()
+        && !value.chars().any(char::is_whitespace)
+}
+
+impl<FD: FormToolData, T: PartialOrd<T> + Display + 'static, K> ValidationBuilder<FD, T, K> {
     /// Requires the value to be at least `min_value` according to
     /// `PartialOrd`.
     pub fn min_value(mut self, min_value: T) -> Self {
-        self.functions.push(Box::new(move |name, value| {
+        self.functions.push(Box::new(move |_fd, value| {
             if value < &min_value {
-                Err(format!("{} mut be >= {}", name, min_value))
+                Err(ValidationMessage::MinValue(min_value.to_string()))
             } else {
                 Ok(())
             }
@@ -147,9 +728,91 @@ impl<FD: FormToolData, T: PartialOrd<T> + Display + 'static> ValidationBuilder<F
     /// Requires the value to be at most `max_value` according to
     /// `PartialOrd`.
     pub fn max_value(mut self, max_value: T) -> Self {
-        self.functions.push(Box::new(move |name, value| {
+        self.functions.push(Box::new(move |_fd, value| {
             if value > &max_value {
-                Err(format!("{} mut be <= {}", name, max_value))
+                Err(ValidationMessage::MaxValue(max_value.to_string()))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+}
+
+impl<FD: FormToolData, T: PartialOrd<T> + 'static, K> ValidationBuilder<FD, T, K> {
+    /// Requires the value to be greater than the value of another field,
+    /// according to `PartialOrd`.
+    ///
+    /// `other_name` is the name of the other field, for error messages.
+    pub fn greater_than_field(
+        mut self,
+        other: impl Fn(&FD) -> &T + 'static,
+        other_name: impl ToString,
+    ) -> Self {
+        let other_name = other_name.to_string();
+        self.functions.push(Box::new(move |fd, value| {
+            if value <= other(fd) {
+                Err(ValidationMessage::GreaterThanField(other_name.clone()))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the value to be less than the value of another field,
+    /// according to `PartialOrd`.
+    ///
+    /// `other_name` is the name of the other field, for error messages.
+    pub fn less_than_field(
+        mut self,
+        other: impl Fn(&FD) -> &T + 'static,
+        other_name: impl ToString,
+    ) -> Self {
+        let other_name = other_name.to_string();
+        self.functions.push(Box::new(move |fd, value| {
+            if value >= other(fd) {
+                Err(ValidationMessage::LessThanField(other_name.clone()))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the value to be at least the value of another field,
+    /// according to `PartialOrd`.
+    ///
+    /// `other_name` is the name of the other field, for error messages.
+    pub fn at_least_field(
+        mut self,
+        other: impl Fn(&FD) -> &T + 'static,
+        other_name: impl ToString,
+    ) -> Self {
+        let other_name = other_name.to_string();
+        self.functions.push(Box::new(move |fd, value| {
+            if value < other(fd) {
+                Err(ValidationMessage::AtLeastField(other_name.clone()))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the value to be at most the value of another field,
+    /// according to `PartialOrd`.
+    ///
+    /// `other_name` is the name of the other field, for error messages.
+    pub fn at_most_field(
+        mut self,
+        other: impl Fn(&FD) -> &T + 'static,
+        other_name: impl ToString,
+    ) -> Self {
+        let other_name = other_name.to_string();
+        self.functions.push(Box::new(move |fd, value| {
+            if value > other(fd) {
+                Err(ValidationMessage::AtMostField(other_name.clone()))
             } else {
                 Ok(())
             }
@@ -158,12 +821,12 @@ impl<FD: FormToolData, T: PartialOrd<T> + Display + 'static> ValidationBuilder<F
     }
 }
 
-impl<FD: FormToolData, T: PartialEq<T> + Display + 'static> ValidationBuilder<FD, T> {
+impl<FD: FormToolData, T: PartialEq<T> + Display + 'static, K> ValidationBuilder<FD, T, K> {
     /// Requires the field to be in the provided whitelist.
     pub fn whitelist(mut self, whitelist: Vec<T>) -> Self {
-        self.functions.push(Box::new(move |name, value| {
+        self.functions.push(Box::new(move |_fd, value| {
             if !whitelist.contains(value) {
-                Err(format!("{} cannot be {}", name, value))
+                Err(ValidationMessage::NotAllowed(value.to_string()))
             } else {
                 Ok(())
             }
@@ -173,9 +836,9 @@ impl<FD: FormToolData, T: PartialEq<T> + Display + 'static> ValidationBuilder<FD
 
     /// Requires the field to not be in the provided blacklist.
     pub fn blacklist(mut self, blacklist: Vec<T>) -> Self {
-        self.functions.push(Box::new(move |name, value| {
+        self.functions.push(Box::new(move |_fd, value| {
             if blacklist.contains(value) {
-                Err(format!("{} cannot be {}", name, value))
+                Err(ValidationMessage::NotAllowed(value.to_string()))
             } else {
                 Ok(())
             }
@@ -183,3 +846,294 @@ impl<FD: FormToolData, T: PartialEq<T> + Display + 'static> ValidationBuilder<FD
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{styles::GridFormStyle, FormBuilder};
+
+    #[derive(Clone, Default)]
+    struct SignUp {
+        password: String,
+        confirm: String,
+        old_password: String,
+        phone: String,
+        email: Option<String>,
+        start: u32,
+        end: u32,
+        tags: Vec<String>,
+        nickname: Option<String>,
+    }
+
+    impl FormToolData for SignUp {
+        type Style = GridFormStyle;
+        type Context = ();
+
+        fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+            fb
+        }
+    }
+
+    #[test]
+    fn email_shapes() {
+        assert!(is_email("user@example.com"));
+        assert!(is_email("first.last+tag@mail.example-site.org"));
+
+        assert!(!is_email(""));
+        assert!(!is_email("user"));
+        assert!(!is_email("@example.com"));
+        assert!(!is_email("user@"));
+        assert!(!is_email("user@example"));
+        assert!(!is_email("user@example..com"));
+        assert!(!is_email("a@b@c.com"));
+        assert!(!is_email("us er@example.com"));
+        assert!(!is_email("user@exa_mple.com"));
+    }
+
+    #[test]
+    fn url_shapes() {
+        assert!(is_url("https://example.com"));
+        assert!(is_url("http://localhost:8080/path?query#hash"));
+        assert!(is_url("git+ssh://host/repo"));
+
+        assert!(!is_url(""));
+        assert!(!is_url("example.com"));
+        assert!(!is_url("https://"));
+        assert!(!is_url("https:///path"));
+        assert!(!is_url("1http://example.com"));
+        assert!(!is_url("https://exa mple.com"));
+    }
+
+    #[test]
+    fn range_bounds() {
+        assert_eq!(check_range(&5, &(1..=10)), Ok(()));
+        assert_eq!(check_range(&1, &(1..=10)), Ok(()));
+        assert_eq!(check_range(&10, &(1..=10)), Ok(()));
+        assert_eq!(
+            check_range(&0, &(1..=10)),
+            Err(ValidationMessage::MinValue(String::from("1")))
+        );
+        assert_eq!(
+            check_range(&11, &(1..=10)),
+            Err(ValidationMessage::MaxValue(String::from("10")))
+        );
+    }
+
+    #[test]
+    fn range_rejects_nan() {
+        let nan = "NaN".parse::<f64>().unwrap();
+        assert_eq!(
+            check_range(&nan, &(0.0..=1.0)),
+            Err(ValidationMessage::MinValue(String::from("0")))
+        );
+    }
+
+    #[test]
+    fn equals_field() {
+        let validate = ValidationBuilder::for_field(|fd: &SignUp| fd.confirm.as_str())
+            .named("Confirm")
+            .equals_field(|fd: &SignUp| fd.password.as_str(), "Password")
+            .build();
+        let mut fd = SignUp {
+            password: String::from("hunter2"),
+            confirm: String::from("hunter2"),
+            ..SignUp::default()
+        };
+        assert_eq!(validate(&fd), Ok(()));
+        fd.confirm = String::from("hunter3");
+        assert_eq!(
+            validate(&fd),
+            Err(String::from("Confirm must match Password"))
+        );
+    }
+
+    #[test]
+    fn not_equal_field() {
+        let validate = ValidationBuilder::for_field(|fd: &SignUp| fd.password.as_str())
+            .named("Password")
+            .not_equal_field(|fd: &SignUp| fd.old_password.as_str(), "Old Password")
+            .build();
+        let mut fd = SignUp {
+            password: String::from("new"),
+            old_password: String::from("old"),
+            ..SignUp::default()
+        };
+        assert_eq!(validate(&fd), Ok(()));
+        fd.password = String::from("old");
+        assert_eq!(
+            validate(&fd),
+            Err(String::from("Password must be different from Old Password"))
+        );
+    }
+
+    #[test]
+    fn required_if_set() {
+        let validate = ValidationBuilder::for_field(|fd: &SignUp| fd.phone.as_str())
+            .named("Phone")
+            .required_if_set(|fd: &SignUp| fd.email.is_some(), "Email")
+            .build();
+        let mut fd = SignUp::default();
+        assert_eq!(validate(&fd), Ok(()));
+        fd.email = Some(String::from("user@example.com"));
+        assert_eq!(
+            validate(&fd),
+            Err(String::from("Phone is required when Email is set"))
+        );
+        fd.phone = String::from("555-0100");
+        assert_eq!(validate(&fd), Ok(()));
+
+        let validate = ValidationBuilder::for_field(|fd: &SignUp| &fd.email)
+            .named("Email")
+            .required_if_set(|fd: &SignUp| !fd.phone.is_empty(), "Phone")
+            .build();
+        fd.email = None;
+        assert_eq!(
+            validate(&fd),
+            Err(String::from("Email is required when Phone is set"))
+        );
+        fd.phone = String::new();
+        assert_eq!(validate(&fd), Ok(()));
+    }
+
+    #[test]
+    fn ordered_fields() {
+        let fd = |start, end| SignUp {
+            start,
+            end,
+            ..SignUp::default()
+        };
+
+        let greater = ValidationBuilder::for_field(|fd: &SignUp| &fd.end)
+            .named("End")
+            .greater_than_field(|fd: &SignUp| &fd.start, "Start")
+            .build();
+        assert_eq!(greater(&fd(1, 2)), Ok(()));
+        assert_eq!(
+            greater(&fd(2, 2)),
+            Err(String::from("End must be greater than Start"))
+        );
+
+        let at_least = ValidationBuilder::for_field(|fd: &SignUp| &fd.end)
+            .named("End")
+            .at_least_field(|fd: &SignUp| &fd.start, "Start")
+            .build();
+        assert_eq!(at_least(&fd(2, 2)), Ok(()));
+        assert_eq!(
+            at_least(&fd(3, 2)),
+            Err(String::from("End must be at least Start"))
+        );
+
+        let less = ValidationBuilder::for_field(|fd: &SignUp| &fd.start)
+            .named("Start")
+            .less_than_field(|fd: &SignUp| &fd.end, "End")
+            .build();
+        assert_eq!(less(&fd(1, 2)), Ok(()));
+        assert_eq!(
+            less(&fd(2, 2)),
+            Err(String::from("Start must be less than End"))
+        );
+
+        let at_most = ValidationBuilder::for_field(|fd: &SignUp| &fd.start)
+            .named("Start")
+            .at_most_field(|fd: &SignUp| &fd.end, "End")
+            .build();
+        assert_eq!(at_most(&fd(2, 2)), Ok(()));
+        assert_eq!(
+            at_most(&fd(3, 2)),
+            Err(String::from("Start must be at most End"))
+        );
+    }
+
+    #[test]
+    fn unique_items() {
+        let validate = ValidationBuilder::for_field(|fd: &SignUp| &fd.tags)
+            .named("Tags")
+            .unique()
+            .build();
+        let fd = |tags: &[&str]| SignUp {
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..SignUp::default()
+        };
+        assert_eq!(validate(&fd(&[])), Ok(()));
+        assert_eq!(validate(&fd(&["a", "b", "c"])), Ok(()));
+        assert_eq!(
+            validate(&fd(&["a", "b", "a", "b"])),
+            Err(String::from("Tags item 3 is a duplicate"))
+        );
+    }
+
+    #[test]
+    fn each_item() {
+        let validate = ValidationBuilder::for_field(|fd: &SignUp| &fd.tags)
+            .named("Tags")
+            .max_items(3)
+            .each(|item| item.as_str(|tag| tag.required().min_len(2)))
+            .build();
+        let fd = |tags: &[&str]| SignUp {
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..SignUp::default()
+        };
+        assert_eq!(validate(&fd(&["ab", "cd"])), Ok(()));
+        assert_eq!(
+            validate(&fd(&["ab", "c"])),
+            Err(String::from("Tags must be >= 2 characters (item 2)"))
+        );
+        assert_eq!(
+            validate(&fd(&["", "c"])),
+            Err(String::from("Tags is required (item 1)"))
+        );
+        assert_eq!(
+            validate(&fd(&["ab", "cd", "ef", "gh"])),
+            Err(String::from("Tags must have at most 3 items"))
+        );
+    }
+
+    #[test]
+    fn some_skips_none() {
+        let validate = ValidationBuilder::for_field(|fd: &SignUp| &fd.nickname)
+            .named("Nickname")
+            .some(|nickname| nickname.as_str(|nickname| nickname.min_len(3)))
+            .build();
+        let mut fd = SignUp::default();
+        assert_eq!(validate(&fd), Ok(()));
+        fd.nickname = Some(String::from("al"));
+        assert_eq!(
+            validate(&fd),
+            Err(String::from("Nickname must be >= 3 characters"))
+        );
+        fd.nickname = Some(String::from("alex"));
+        assert_eq!(validate(&fd), Ok(()));
+    }
+
+    #[test]
+    fn nested_name_and_catalog() {
+        struct Codes;
+
+        impl MessageCatalog for Codes {
+            fn message(&self, field: &str, message: &ValidationMessage) -> String {
+                format!("{}:{}", field, message.code())
+            }
+        }
+
+        let fd = SignUp {
+            tags: vec![String::from("a")],
+            ..SignUp::default()
+        };
+
+        let named = ValidationBuilder::for_field(|fd: &SignUp| &fd.tags)
+            .named("Tags")
+            .each(|item| item.named("Tag").as_str(|tag| tag.min_len(2)))
+            .build();
+        assert_eq!(
+            named(&fd),
+            Err(String::from("Tag must be >= 2 characters (item 1)"))
+        );
+
+        let catalog = ValidationBuilder::for_field(|fd: &SignUp| &fd.tags)
+            .named("Tags")
+            .messages(Codes)
+            .each(|item| item.as_str(|tag| tag.min_len(2)))
+            .build();
+        assert_eq!(catalog(&fd), Err(String::from("Tags:item")));
+    }
+}